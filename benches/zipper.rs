@@ -0,0 +1,171 @@
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use zippered::arena::ArenaZipper;
+use zippered::zipper::*;
+
+/// Counts allocations, so that each benchmark can report how much one run of it allocates.
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+/// A tree whose nodes are cheap to clone, so that the benchmarks measure the zipper rather than the tree.
+#[derive(Clone)]
+struct Tree(Rc<Vec<Tree>>);
+
+impl Zippable for Tree {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.0.iter().cloned()
+    }
+}
+
+/// A tree of `depth` levels below the root in which every branch has `width` children.
+fn tree(width: usize, depth: usize) -> Tree {
+    let children = match depth {
+        0 => Vec::new(),
+        _ => (0..width).map(|_| tree(width, depth - 1)).collect(),
+    };
+    Tree(Rc::new(children))
+}
+
+/// Benchmarks `run` as `name` within `group`, titled `title`, first printing the allocations of a single run.
+fn bench<R>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    title: &str,
+    name: &str,
+    run: impl Fn() -> R,
+) {
+    let (allocations, bytes) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        BYTES.load(Ordering::Relaxed),
+    );
+    black_box(run());
+    eprintln!(
+        "{title}/{name}: {} allocations, {} bytes",
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        BYTES.load(Ordering::Relaxed) - bytes,
+    );
+
+    group.bench_function(name, |b| b.iter(&run));
+}
+
+/// Moves down to the bottom of a single branch and up again, with either kind of zipper.
+macro_rules! plumb {
+    ($zipper:expr) => {{
+        let mut zipper = $zipper;
+        while let Ok(down) = zipper.clone().down() {
+            zipper = down;
+        }
+        while let Ok(up) = zipper.clone().up() {
+            zipper = up;
+        }
+        zipper
+    }};
+}
+
+/// Moves right across the siblings of the first child and left again, with either kind of zipper.
+macro_rules! sweep {
+    ($zipper:expr) => {{
+        let mut zipper = $zipper.down().unwrap();
+        while let Ok(right) = zipper.clone().right() {
+            zipper = right;
+        }
+        while let Ok(left) = zipper.clone().left() {
+            zipper = left;
+        }
+        zipper
+    }};
+}
+
+/// Visits every value in depth-first pre-order by moving down, right and up, with either kind of zipper,
+/// returning the number of values visited.
+macro_rules! walk {
+    ($zipper:expr) => {{
+        let (mut zipper, mut visited) = ($zipper, 1);
+        'walk: loop {
+            if let Ok(down) = zipper.clone().down() {
+                (zipper, visited) = (down, visited + 1);
+                continue;
+            }
+            loop {
+                if let Ok(right) = zipper.clone().right() {
+                    (zipper, visited) = (right, visited + 1);
+                    break;
+                }
+                match zipper.up() {
+                    Ok(up) => zipper = up,
+                    Err(_) => break 'walk visited,
+                }
+            }
+        }
+    }};
+}
+
+fn deep(c: &mut Criterion) {
+    let deep = tree(1, 1_000);
+    let title = "deep: down to the bottom and up again";
+    let mut group = c.benchmark_group(title);
+
+    bench(&mut group, title, "zipper", || plumb!(deep.zipper()));
+    bench(&mut group, title, "arena", || {
+        plumb!(ArenaZipper::new(deep.clone()))
+    });
+    group.finish();
+}
+
+fn wide(c: &mut Criterion) {
+    let wide = tree(1_000, 1);
+    let title = "wide: right across the children and left again";
+    let mut group = c.benchmark_group(title);
+
+    bench(&mut group, title, "zipper", || sweep!(wide.zipper()));
+    bench(&mut group, title, "arena", || {
+        sweep!(ArenaZipper::new(wide.clone()))
+    });
+    group.finish();
+}
+
+fn walk(c: &mut Criterion) {
+    let complete = tree(4, 6);
+    let title = "walk: every value of a complete tree by moving";
+    let mut group = c.benchmark_group(title);
+
+    bench(&mut group, title, "zipper", || walk!(complete.zipper()));
+    bench(&mut group, title, "arena", || {
+        walk!(ArenaZipper::new(complete.clone()))
+    });
+    group.finish();
+}
+
+fn descendants(c: &mut Criterion) {
+    let complete = tree(4, 6);
+    let title = "descendants: every node of a complete tree";
+    let mut group = c.benchmark_group(title);
+
+    bench(&mut group, title, "zipper", || {
+        complete.zipper().descendants().count()
+    });
+    group.finish();
+}
+
+criterion_group!(benches, deep, wide, walk, descendants);
+criterion_main!(benches);
@@ -0,0 +1,241 @@
+//! An alternative to [Zipper] for very large trees, storing every value it lists in a single arena rather than
+//! allocating a frame per level and a cache entry per visited position.
+//!
+//! An [ArenaZipper] is a handle into the arena: the index of its focus, along with its [path](ArenaZipper::path)
+//! and [journey](ArenaZipper::journey). The arena keeps each value's parent index, and lays out the children of a
+//! value next to one another, so that moving to a sibling is a matter of adding or subtracting one. Cloning an
+//! [ArenaZipper] shares its arena, in the same way as the [Zipper]s of a [ZipperSession](crate::zipper::ZipperSession)
+//! share their cache.
+//!
+//! ```
+//! use zippered::arena::ArenaZipper;
+//! use zippered::zipper::{Step::*, *};
+//!
+//! let tree = Unfold::new(1, |&n: &usize| if n < 4 { vec![n * 2, n * 2 + 1] } else { vec![] });
+//!
+//! let arena = ArenaZipper::new(tree).down()?.right()?.down()?.up()?;
+//! assert_eq!(arena.node().value, 3);
+//! assert_eq!(arena.path().collect::<Vec<_>>(), vec![Down, Right]);
+//! assert_eq!(arena.journey().collect::<Vec<_>>(), vec![Down, Right, Down, Up]);
+//! # Ok::<(), ZipperErr>(())
+//! ```
+//!
+//! Unlike a [Zipper], an [ArenaZipper] cannot pivot [up to](Step::UpTo) one of a value's
+//! [parents](Zippable::parents), and lists all children of a value the first time it moves down from it.
+
+#[cfg(doc)]
+use crate::zipper::Zipper;
+use crate::zipper::{History, Step, Zippable, ZipperErr};
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+/// The values listed by the [ArenaZipper]s sharing it, in the order they were listed.
+struct Arena<T> {
+    values: Vec<T>,
+    /// The index of the parent of each value, `None` for the root
+    parents: Vec<Option<usize>>,
+    /// The indices of the children of each value, `None` until they are listed
+    children: Vec<Option<Range<usize>>>,
+}
+
+impl<T> Arena<T>
+where
+    T: Zippable,
+{
+    fn new(root: T) -> Self {
+        Self {
+            values: vec![root],
+            parents: vec![None],
+            children: vec![None],
+        }
+    }
+
+    /// The indices of the children of the value at `index`, listing them into the arena if they are not yet.
+    fn children(&mut self, index: usize) -> Range<usize> {
+        if let Some(children) = &self.children[index] {
+            return children.clone();
+        }
+
+        let start = self.values.len();
+        let value = self.values[index].clone();
+        self.values.extend(value.children());
+
+        let end = self.values.len();
+        self.parents.resize(end, Some(index));
+        self.children.resize(end, None);
+        self.children[index] = Some(start..end);
+
+        start..end
+    }
+
+    /// The indices of the value at `index` and its siblings, or `None` for the root.
+    fn siblings(&self, index: usize) -> Option<Range<usize>> {
+        let parent = self.parents[index]?;
+        self.children[parent].clone()
+    }
+}
+
+/// Can [step](#method.step) or [travel](#method.travel) like a [Zipper], keeping the values it lists in an arena
+/// shared by all of its clones. See the [module documentation](self).
+#[derive(Clone)]
+pub struct ArenaZipper<T>
+where
+    T: Zippable,
+{
+    arena: Rc<RefCell<Arena<T>>>,
+    focus: usize,
+    history: History,
+}
+
+impl<T> ArenaZipper<T>
+where
+    T: Zippable,
+{
+    /// Creates an [ArenaZipper] positioned at `root`, in an arena of its own.
+    pub fn new(root: T) -> Self {
+        Self {
+            arena: Rc::new(RefCell::new(Arena::new(root))),
+            focus: 0,
+            history: History::new(0),
+        }
+    }
+
+    /// The current value, cloned from the arena so that no borrow of the arena outlives the call.
+    pub fn node(&self) -> T {
+        self.arena.borrow().values[self.focus].clone()
+    }
+
+    /// Moves the focus to the value at `index`, recording `step`.
+    fn to(mut self, index: usize, step: Step) -> Self {
+        self.focus = index;
+        self.history = self.history.step(step);
+        self
+    }
+
+    /// Returns a new ArenaZipper after moving down to this value's first child, or [ZipperErr::CannotGoDown] if
+    /// no children can or do exist.
+    pub fn down(self) -> Result<ArenaZipper<T>, ZipperErr> {
+        let children = self.arena.borrow_mut().children(self.focus);
+
+        match children.is_empty() {
+            true => Err(ZipperErr::CannotGoDown),
+            false => Ok(self.to(children.start, Step::Down)),
+        }
+    }
+
+    /// Returns a new ArenaZipper after moving up to this value's parent, or [ZipperErr::CannotGoUp] if already at
+    /// the root / top of the tree.
+    pub fn up(self) -> Result<ArenaZipper<T>, ZipperErr> {
+        let parent = self.arena.borrow().parents[self.focus];
+
+        match parent {
+            Some(parent) => Ok(self.to(parent, Step::Up)),
+            None => Err(ZipperErr::CannotGoUp),
+        }
+    }
+
+    /// Returns a new ArenaZipper after moving right to this value's next sibling, or [ZipperErr::CannotGoRight] if
+    /// no right sibling exists.
+    pub fn right(self) -> Result<ArenaZipper<T>, ZipperErr> {
+        let siblings = self.arena.borrow().siblings(self.focus);
+
+        match siblings {
+            Some(siblings) if self.focus + 1 < siblings.end => {
+                let right = self.focus + 1;
+                Ok(self.to(right, Step::Right))
+            }
+            _ => Err(ZipperErr::CannotGoRight),
+        }
+    }
+
+    /// Returns a new ArenaZipper after moving left to this value's previous sibling, or [ZipperErr::CannotGoLeft]
+    /// if no left sibling exists.
+    pub fn left(self) -> Result<ArenaZipper<T>, ZipperErr> {
+        let siblings = self.arena.borrow().siblings(self.focus);
+
+        match siblings {
+            Some(siblings) if self.focus > siblings.start => {
+                let left = self.focus - 1;
+                Ok(self.to(left, Step::Left))
+            }
+            _ => Err(ZipperErr::CannotGoLeft),
+        }
+    }
+
+    /// Returns a new ArenaZipper after moving to the step prior the current value, or [ZipperErr::CannotGoBack]
+    /// if there have not yet been any [Step]s taken.
+    pub fn back(self) -> Result<ArenaZipper<T>, ZipperErr> {
+        // the path only ever holds a Down step per level, followed by a Right step per preceding sibling
+        let previous = match self.history.path.last() {
+            Some(Step::Down) => self.arena.borrow().parents[self.focus],
+            Some(Step::Right) => Some(self.focus - 1),
+            _ => None,
+        };
+
+        match previous {
+            Some(previous) => Ok(self.to(previous, Step::Back)),
+            None => Err(ZipperErr::CannotGoBack),
+        }
+    }
+
+    /// Returns a new ArenaZipper after taking the specified [Step], or the relevant [ZipperErr] if it cannot be
+    /// taken. [Step::UpTo] always fails with [ZipperErr::CannotGoUp].
+    pub fn step(self, step: &Step) -> Result<ArenaZipper<T>, ZipperErr> {
+        match step {
+            Step::Up => self.up(),
+            Step::Down => self.down(),
+            Step::Left => self.left(),
+            Step::Right => self.right(),
+            Step::Back => self.back(),
+            Step::UpTo(_) => Err(ZipperErr::CannotGoUp),
+        }
+    }
+
+    /// Return a new ArenaZipper after taking the specified sequence of [Step]s, or the relevant [ZipperErr] if
+    /// any step cannot be taken.
+    pub fn travel(self, path: impl Iterator<Item = Step>) -> Result<ArenaZipper<T>, ZipperErr> {
+        let mut zipper = self;
+
+        for step in path {
+            zipper = zipper.step(&step)?;
+        }
+
+        Ok(zipper)
+    }
+
+    /// The most direct sequence of [Step]s taken to reach the ArenaZipper's current position.
+    pub fn path(&self) -> impl Iterator<Item = Step> + '_ {
+        self.history.path.iter().cloned()
+    }
+
+    /// The exact sequence of [Step]s taken to reach the ArenaZipper's current position.
+    ///
+    /// This differs from [path](#method.path) in that it includes backtracking steps
+    pub fn journey(&self) -> impl Iterator<Item = Step> + '_ {
+        self.history.journey.iter().cloned()
+    }
+
+    /// The number of levels between the ArenaZipper's current position and the root.
+    pub fn depth(&self) -> usize {
+        let arena = self.arena.borrow();
+        std::iter::successors(arena.parents[self.focus], |&parent| arena.parents[parent]).count()
+    }
+
+    /// The position of the current value amongst its siblings, or `None` at the root.
+    pub fn index(&self) -> Option<usize> {
+        let siblings = self.arena.borrow().siblings(self.focus)?;
+        Some(self.focus - siblings.start)
+    }
+
+    /// Whether the ArenaZipper is positioned at the root / top of the tree.
+    pub fn is_root(&self) -> bool {
+        self.arena.borrow().parents[self.focus].is_none()
+    }
+
+    /// The number of values stored in the arena, i.e. the root and every child listed so far by this
+    /// ArenaZipper or any of its clones.
+    pub fn stored(&self) -> usize {
+        self.arena.borrow().values.len()
+    }
+}
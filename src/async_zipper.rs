@@ -0,0 +1,301 @@
+//! The `AsyncZippable` trait for creating an `AsyncZipper` from a tree-like structure whose children are fetched
+//! asynchronously, such as objects in a store or rows in a database. Requires the `async` feature.
+//!
+//! An [AsyncZipper] moves like a [Zipper](crate::zipper::Zipper), with the same [Step]s, [path](AsyncZipper::path),
+//! [journey](AsyncZipper::journey) and cache of visited positions, but each move is an `async fn`. It does not
+//! depend on any particular runtime; its futures are not `Send`, so drive them with a local executor such as
+//! `futures::executor::block_on` or a runtime's `spawn_local`.
+//!
+//! ```
+//! use futures::stream::{self, Stream};
+//! use zippered::async_zipper::*;
+//! use zippered::zipper::ZipperErr;
+//!
+//! #[derive(Clone)]
+//! struct Number(usize);
+//!
+//! impl AsyncZippable for Number {
+//!     fn children(&self) -> impl Stream<Item = Self> + '_ {
+//!         stream::iter((1..=self.0.min(3)).map(|n| Number(self.0 * 10 + n)))
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), ZipperErr> {
+//! futures::executor::block_on(async {
+//!     let zipper = Number(2).zipper().down().await?.right().await?;
+//!     assert_eq!(zipper.node.0, 22);
+//!     Ok(())
+//! })
+//! # }
+//! ```
+
+use crate::zipper::{History, SingletonNodeCache, Step, ZipperErr};
+use futures_core::Stream;
+use std::future::poll_fn;
+use std::pin::pin;
+use std::rc::Rc;
+use std::task::Poll;
+
+/// A trait for describing how a type can be made capable of producing an [AsyncZipper].
+///
+/// Any type that can describe streaming its children via a [Stream] can be made [AsyncZippable].
+pub trait AsyncZippable
+where
+    Self: Clone,
+{
+    /// Returns the children of the value. An empty [Stream] can be used to signal that a node cannot or does
+    /// not have children.
+    fn children(&self) -> impl Stream<Item = Self> + '_;
+
+    /// Creates and returns an [AsyncZipper] for this value
+    fn zipper(&self) -> AsyncZipper<Self> {
+        AsyncZipper {
+            node: self.clone(),
+            history: History::new(0),
+            parent: None,
+            index_in_parent: None,
+            cache: SingletonNodeCache::new(),
+        }
+    }
+}
+
+/// The `index`th item of `stream`, if it has one, polling no further than that item.
+async fn nth<S>(stream: S, index: usize) -> Option<S::Item>
+where
+    S: Stream,
+{
+    let mut stream = pin!(stream);
+    let mut skipped = 0;
+
+    poll_fn(|cx| loop {
+        match stream.as_mut().poll_next(cx) {
+            Poll::Ready(Some(item)) if skipped == index => return Poll::Ready(Some(item)),
+            Poll::Ready(Some(_)) => skipped += 1,
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => return Poll::Pending,
+        }
+    })
+    .await
+}
+
+/// A level above an [AsyncZipper]'s position: a value whose children are being fetched, along with its own
+/// position amongst its siblings. Unlike an [AsyncZipper], a frame holds no handle to the cache, so the cache can
+/// hold frames without holding itself.
+#[derive(Clone)]
+struct Frame<T> {
+    node: T,
+    index: Option<usize>,
+    parent: Option<Rc<Frame<T>>>,
+}
+
+impl<T> Frame<T>
+where
+    T: AsyncZippable,
+{
+    /// The [Frame] of `zipper`'s position.
+    fn of(zipper: &AsyncZipper<T>) -> Self {
+        Frame {
+            node: zipper.node.clone(),
+            index: zipper.index_in_parent,
+            parent: zipper.parent.clone(),
+        }
+    }
+
+    /// An [AsyncZipper] positioned at this frame's value, reached by `history`.
+    fn zipper(&self, history: History, cache: SingletonNodeCache<Frame<T>>) -> AsyncZipper<T> {
+        AsyncZipper {
+            node: self.node.clone(),
+            history,
+            parent: self.parent.clone(),
+            index_in_parent: self.index,
+            cache,
+        }
+    }
+}
+
+/// A cursor over a tree structure of [AsyncZippable]s, moved asynchronously. See [Zipper](crate::zipper::Zipper).
+#[derive(Clone)]
+pub struct AsyncZipper<T>
+where
+    T: AsyncZippable,
+{
+    pub node: T,
+    history: History,
+    parent: Option<Rc<Frame<T>>>,
+    index_in_parent: Option<usize>,
+    cache: SingletonNodeCache<Frame<T>>,
+}
+
+impl<T> AsyncZipper<T>
+where
+    T: AsyncZippable,
+{
+    /// Returns a new AsyncZipper positioned at the sibling of the current value at `index`, fetching it from the
+    /// parent's children unless `history` was visited before.
+    async fn sibling(
+        self,
+        index: usize,
+        history: History,
+        err: ZipperErr,
+    ) -> Result<AsyncZipper<T>, ZipperErr> {
+        if let Some(cached) = self.cache.find(&history) {
+            return Ok(cached.zipper(history, self.cache));
+        }
+
+        let parent = self.parent.clone().ok_or(err.clone())?;
+        let node = nth(parent.node.children(), index).await.ok_or(err)?;
+
+        let next = AsyncZipper {
+            node,
+            parent: Some(parent),
+            index_in_parent: Some(index),
+            history,
+            cache: self.cache.clone(),
+        };
+
+        // add to cache
+        self.cache.insert(&next.history, Frame::of(&next));
+
+        Ok(next)
+    }
+
+    /// Returns a new AsyncZipper after moving down to this value's first child, or [ZipperErr::CannotGoDown] if
+    /// no children can or do exist.
+    pub async fn down(self) -> Result<AsyncZipper<T>, ZipperErr> {
+        // this is where we want to go
+        let next_history = self.history.clone().step(Step::Down);
+        // check cache and return if possible
+        if let Some(cached) = self.cache.find(&next_history) {
+            return Ok(cached.zipper(next_history, self.cache));
+        }
+
+        let first = nth(self.node.children(), 0)
+            .await
+            .ok_or(ZipperErr::CannotGoDown)?;
+
+        let next = AsyncZipper {
+            node: first,
+            parent: Some(Rc::new(Frame::of(&self))),
+            index_in_parent: Some(0),
+            history: next_history,
+            cache: self.cache.clone(),
+        };
+
+        // add to cache
+        self.cache.insert(&next.history, Frame::of(&next));
+
+        Ok(next)
+    }
+
+    /// Returns a new AsyncZipper after moving up to this value's parent, or [ZipperErr::CannotGoUp] if already at
+    /// the root / top of the tree.
+    pub async fn up(self) -> Result<AsyncZipper<T>, ZipperErr> {
+        match self.parent {
+            Some(ref parent) => Ok(AsyncZipper {
+                node: parent.node.clone(),
+                parent: parent.parent.clone(),
+                index_in_parent: parent.index,
+                history: self.history.step(Step::Up),
+                cache: self.cache,
+            }),
+            None => Err(ZipperErr::CannotGoUp),
+        }
+    }
+
+    /// Returns a new AsyncZipper after moving right to this value's next sibling, or [ZipperErr::CannotGoRight]
+    /// if no right sibling exists.
+    pub async fn right(self) -> Result<AsyncZipper<T>, ZipperErr> {
+        let index = self.index_in_parent.ok_or(ZipperErr::CannotGoRight)? + 1;
+        let next_history = self.history.clone().step(Step::Right);
+        self.sibling(index, next_history, ZipperErr::CannotGoRight)
+            .await
+    }
+
+    /// Returns a new AsyncZipper after moving left to this value's previous sibling, or [ZipperErr::CannotGoLeft]
+    /// if no left sibling exists.
+    pub async fn left(self) -> Result<AsyncZipper<T>, ZipperErr> {
+        let index = match self.index_in_parent {
+            Some(index) if index > 0 => index - 1,
+            _ => return Err(ZipperErr::CannotGoLeft),
+        };
+        let next_history = self.history.clone().step(Step::Left);
+        self.sibling(index, next_history, ZipperErr::CannotGoLeft)
+            .await
+    }
+
+    /// Returns a new AsyncZipper after moving to the step prior the current value, or [ZipperErr::CannotGoBack]
+    /// if there have not yet been any [Step]s taken.
+    pub async fn back(self) -> Result<AsyncZipper<T>, ZipperErr> {
+        // this is where we want to go
+        let next_history = self.history.clone().step(Step::Back);
+
+        // check cache and return if possible
+        if let Some(cached) = self.cache.find(&next_history) {
+            return Ok(cached.zipper(next_history, self.cache));
+        }
+
+        // there is no traversal path, we are at the top, use parent if it exists
+        match self.parent {
+            Some(parent) if next_history.path.is_empty() => {
+                Ok(parent.zipper(next_history, self.cache))
+            }
+            _ => Err(ZipperErr::CannotGoBack),
+        }
+    }
+
+    /// Return a new AsyncZipper after taking a [Step] in the specified direction, or the relevant [ZipperErr] if
+    /// no step can be taken. [AsyncZippable]s have no other parents, so [Step::UpTo] fails with
+    /// [ZipperErr::CannotGoUp].
+    pub async fn step(self, step: &Step) -> Result<AsyncZipper<T>, ZipperErr> {
+        match step {
+            Step::Up => self.up().await,
+            Step::Down => self.down().await,
+            Step::Left => self.left().await,
+            Step::Right => self.right().await,
+            Step::Back => self.back().await,
+            Step::UpTo(_) => Err(ZipperErr::CannotGoUp),
+        }
+    }
+
+    /// Return a new AsyncZipper after taking the specified sequence of [Step]s, or the relevant [ZipperErr] if
+    /// any step cannot be taken.
+    pub async fn travel(
+        self,
+        path: impl Iterator<Item = Step>,
+    ) -> Result<AsyncZipper<T>, ZipperErr> {
+        let mut zipper = self;
+
+        for step in path {
+            zipper = zipper.step(&step).await?;
+        }
+
+        Ok(zipper)
+    }
+
+    /// The most direct sequence of [Step]s taken to reach the [AsyncZipper]'s current position.
+    pub fn path(&self) -> impl Iterator<Item = Step> + '_ {
+        self.history.path.iter().cloned()
+    }
+
+    /// The exact sequence of [Step]s taken to reach the [AsyncZipper]'s current position.
+    ///
+    /// This differs from [path](#method.path) in that it includes backtracking steps
+    pub fn journey(&self) -> impl Iterator<Item = Step> + '_ {
+        self.history.journey.iter().cloned()
+    }
+
+    /// The number of levels between the [AsyncZipper]'s current position and the root.
+    pub fn depth(&self) -> usize {
+        std::iter::successors(self.parent.as_deref(), |p| p.parent.as_deref()).count()
+    }
+
+    /// The position of the current value amongst its siblings, or `None` at the root.
+    pub fn index(&self) -> Option<usize> {
+        self.index_in_parent
+    }
+
+    /// Whether the [AsyncZipper] is positioned at the root / top of the tree.
+    pub fn is_root(&self) -> bool {
+        self.parent.is_none()
+    }
+}
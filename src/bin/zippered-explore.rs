@@ -0,0 +1,176 @@
+//! Explores a JSON document or a directory tree from the terminal. Requires the `explore` feature.
+//!
+//! ```text
+//! zippered-explore <file.json | directory>
+//! ```
+//!
+//! Input is read a line at a time, even from a terminal, so keys take effect once Enter is pressed. Each line is a
+//! sequence of keys, travelled in one go: `h` moves left, `j` down, `k` up, `l` right and `b` back, while `q`
+//! quits. After each line the focus, its path and its children are printed. When stdin is not a terminal the
+//! lines are read as a script, so `echo "jl" | zippered-explore data.json` prints the second child of the root.
+
+use serde_json::Value;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use zippered::fs::FsNode;
+use zippered::json::JsonNode;
+use zippered::zipper::{Step, TryZippable, Zippable, Zipper};
+
+const USAGE: &str = "\
+usage: zippered-explore <file.json | directory>
+
+Reads lines of keys from stdin, moving once Enter is pressed: h left, j down, k up, l right, b back, q quit";
+const KEYS: &str = "keys: h left, j down, k up, l right, b back, q quit";
+
+fn main() -> ExitCode {
+    let Some(path) = std::env::args_os().nth(1) else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    match run(Path::new(&path)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("zippered-explore: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Explores the directory or JSON file at `path` with the keys read from stdin.
+fn run(path: &Path) -> Result<(), Box<dyn Error>> {
+    let interactive = io::stdin().is_terminal();
+    let input = io::stdin().lock();
+    let output = io::stdout().lock();
+
+    if path.is_dir() {
+        let root = FsNode::new(path);
+        explore(
+            root.try_zipper(),
+            directory_label,
+            input,
+            output,
+            interactive,
+        )?;
+    } else {
+        let document: Value = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        let root = JsonNode::new(&document);
+        explore(root.zipper(), json_label, input, output, interactive)?;
+    }
+
+    Ok(())
+}
+
+/// Moves `zipper` by the keys of each line of `input`, printing where it arrives to `output`.
+fn explore<T>(
+    mut zipper: Zipper<T>,
+    label: impl Fn(&T) -> String,
+    input: impl BufRead,
+    mut output: impl Write,
+    interactive: bool,
+) -> io::Result<()>
+where
+    T: Zippable,
+{
+    if interactive {
+        writeln!(output, "{KEYS}, each line followed by Enter")?;
+    }
+    show(&zipper, &label, &mut output)?;
+    prompt(&mut output, interactive)?;
+
+    for line in input.lines() {
+        let mut steps = Vec::new();
+        let mut quit = false;
+
+        for key in line?.chars().filter(|key| !key.is_whitespace()) {
+            match key {
+                'h' => steps.push(Step::Left),
+                'j' => steps.push(Step::Down),
+                'k' => steps.push(Step::Up),
+                'l' => steps.push(Step::Right),
+                'b' => steps.push(Step::Back),
+                'q' => quit = true,
+                _ => writeln!(output, "unknown key {key:?}, {KEYS}")?,
+            }
+        }
+
+        if !steps.is_empty() {
+            match zipper.clone().travel(steps.into_iter()) {
+                Ok(next) => zipper = next,
+                Err(err) => writeln!(output, "{err}")?,
+            }
+            show(&zipper, &label, &mut output)?;
+        }
+
+        if quit {
+            break;
+        }
+        prompt(&mut output, interactive)?;
+    }
+
+    Ok(())
+}
+
+/// Asks for the next keys, if a user is there to answer.
+fn prompt(output: &mut impl Write, interactive: bool) -> io::Result<()> {
+    if interactive {
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+    Ok(())
+}
+
+/// Prints the focus of `zipper`, its path and its children.
+fn show<T>(
+    zipper: &Zipper<T>,
+    label: impl Fn(&T) -> String,
+    output: &mut impl Write,
+) -> io::Result<()>
+where
+    T: Zippable,
+{
+    writeln!(output, "focus: {}", label(&zipper.node))?;
+    writeln!(output, "path: {:?}", zipper.path().collect::<Vec<_>>())?;
+
+    let children = zipper.descendants().max_depth(1).collect::<Vec<_>>();
+    if children.is_empty() {
+        writeln!(output, "children: none")?;
+    } else {
+        writeln!(output, "children:")?;
+        for child in children {
+            writeln!(output, "  {}", label(&child.node))?;
+        }
+    }
+
+    writeln!(output)
+}
+
+/// The name of a file or directory, with directories marked by a trailing `/`.
+fn directory_label(node: &FsNode) -> String {
+    let name = node.name().map_or_else(
+        || node.path().display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+
+    match node.is_dir() {
+        true => format!("{name}/"),
+        false => name,
+    }
+}
+
+/// The key of a JSON value along with the value itself, or the size of an array or object.
+fn json_label(node: &JsonNode) -> String {
+    let value = match node.value() {
+        Value::Array(array) => format!("[{} items]", array.len()),
+        Value::Object(object) => format!("{{{} fields}}", object.len()),
+        scalar => scalar.to_string(),
+    };
+
+    match node.key() {
+        Some(key) => format!("{key}: {value}"),
+        None => value,
+    }
+}
@@ -0,0 +1,117 @@
+//! Queries JSON documents by zipper position from the command line. Requires the `cli` feature.
+//!
+//! ```text
+//! zippered get <file.json> <steps | pointer>
+//! zippered paths <file.json>
+//! ```
+//!
+//! `get` prints the value reached by travelling `steps` from the root, written as letters such as `"D R R D"` for
+//! down, right, right and down (`U`, `L` and `B` move up, left and back), or the value at a JSON Pointer such as
+//! `/users/1/name`. `paths` lists the steps to every value below the root, alongside its JSON Pointer.
+
+use serde_json::Value;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufReader, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use zippered::json::JsonNode;
+use zippered::zipper::{Step, Zippable};
+
+const USAGE: &str = "\
+usage: zippered get <file.json> <steps | pointer>
+       zippered paths <file.json>";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["get", file, position] => get(Path::new(file), position),
+        ["paths", file] => paths(Path::new(file)),
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("zippered: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads the JSON document at `file`.
+fn read(file: &Path) -> Result<Value, Box<dyn Error>> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(file)?))?)
+}
+
+/// Parses `steps` written as letters, e.g. `"D R R D"`, ignoring whitespace.
+fn parse(steps: &str) -> Result<Vec<Step>, String> {
+    steps
+        .chars()
+        .filter(|letter| !letter.is_whitespace())
+        .map(|letter| match letter.to_ascii_uppercase() {
+            'U' => Ok(Step::Up),
+            'D' => Ok(Step::Down),
+            'L' => Ok(Step::Left),
+            'R' => Ok(Step::Right),
+            'B' => Ok(Step::Back),
+            _ => Err(format!(
+                "unknown step {letter:?}, expected one of U, D, L, R or B"
+            )),
+        })
+        .collect()
+}
+
+/// Writes `steps` as letters separated by spaces, as [parse] reads them.
+fn letters(steps: impl Iterator<Item = Step>) -> String {
+    steps
+        .map(|step| match step {
+            Step::Up => "U",
+            Step::Down => "D",
+            Step::Left => "L",
+            Step::Right => "R",
+            Step::Back => "B",
+            Step::UpTo(_) => unreachable!("JSON values have a single parent"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Prints the value of the document at `file` found at `position`, either steps or a JSON Pointer.
+fn get(file: &Path, position: &str) -> Result<(), Box<dyn Error>> {
+    let document = read(file)?;
+    let root = JsonNode::new(&document);
+
+    let steps = match position.is_empty() || position.starts_with('/') {
+        true => root
+            .steps_to(position)
+            .ok_or_else(|| format!("no value at {position:?}"))?,
+        false => parse(position)?,
+    };
+    let zipper = root.zipper().travel(steps.into_iter())?;
+
+    writeln!(io::stdout(), "{}", zipper.node.value())?;
+    Ok(())
+}
+
+/// Prints the steps to every value below the root of the document at `file`, each followed by a tab and its
+/// JSON Pointer.
+fn paths(file: &Path) -> Result<(), Box<dyn Error>> {
+    let document = read(file)?;
+    let mut output = io::stdout().lock();
+
+    for zipped in JsonNode::new(&document).zipper().descendants() {
+        writeln!(
+            output,
+            "{}\t{}",
+            letters(zipped.path()),
+            zipped.node.pointer()
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+//! A [Zippable] view of the filesystem. Requires the `fs` feature.
+//!
+//! An [FsNode] is a path whose children are the entries of the directory at that path, read only when the
+//! [Zipper](crate::zipper::Zipper) first moves into them and sorted by file name:
+//!
+//! ```no_run
+//! use zippered::fs::{FsNode, Symlinks};
+//! use zippered::zipper::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let root = FsNode::new("/var/log")
+//!     .symlinks(Symlinks::Follow)
+//!     .ignore("*.gz")?;
+//!
+//! // report unreadable directories rather than treating them as empty
+//! let first = root.try_zipper().down()?.node;
+//! println!("{} is {} bytes", first.path().display(), first.metadata()?.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::zipper::{Edged, Identified, TryZippable, Zippable};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::ffi::{OsStr, OsString};
+use std::fs::{self, Metadata};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// How an [FsNode] treats symbolic links found in a directory.
+#[derive(Debug, Copy, Clone, Default, Hash, PartialEq, Eq)]
+pub enum Symlinks {
+    /// Links are children, but are leaves even when they point to a directory
+    #[default]
+    Keep,
+    /// Links are children, and a link to a directory has that directory's entries as children.
+    /// Pair with [detect_cycles](crate::zipper::ZipperSession::detect_cycles) if links may form a loop
+    Follow,
+    /// Links are not children
+    Skip,
+}
+
+/// Settings shared by every [FsNode] below the same root.
+#[derive(Debug, Clone)]
+struct Options {
+    root: PathBuf,
+    symlinks: Symlinks,
+    globs: Vec<Glob>,
+    ignore: GlobSet,
+}
+
+/// A file, directory or symbolic link, made [Zippable] by listing directory entries.
+///
+/// Entries are read with [fs::read_dir] each time children are needed. A directory that cannot be read (e.g. for
+/// lack of permission) has no children, unless explored via [TryZippable::try_zipper] which reports the error.
+#[derive(Debug, Clone)]
+pub struct FsNode {
+    path: PathBuf,
+    options: Arc<Options>,
+}
+
+impl FsNode {
+    /// Creates a node for `root`, keeping symbolic links as leaves and ignoring nothing.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        Self {
+            path: root.clone(),
+            options: Arc::new(Options {
+                root,
+                symlinks: Symlinks::default(),
+                globs: Vec::new(),
+                ignore: GlobSet::empty(),
+            }),
+        }
+    }
+
+    /// Sets how symbolic links below this node are treated.
+    pub fn symlinks(mut self, symlinks: Symlinks) -> Self {
+        Arc::make_mut(&mut self.options).symlinks = symlinks;
+        self
+    }
+
+    /// Leaves out entries whose path relative to the root matches the glob `pattern`, along with everything
+    /// below them. Can be called any number of times.
+    pub fn ignore(mut self, pattern: &str) -> Result<Self, globset::Error> {
+        let options = Arc::make_mut(&mut self.options);
+        options.globs.push(Glob::new(pattern)?);
+
+        let mut builder = GlobSetBuilder::new();
+        for glob in &options.globs {
+            builder.add(glob.clone());
+        }
+        options.ignore = builder.build()?;
+        Ok(self)
+    }
+
+    /// The path of this node.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The final component of this node's path, if it has one.
+    pub fn name(&self) -> Option<&OsStr> {
+        self.path.file_name()
+    }
+
+    /// The metadata of this node, of the link itself unless links are [followed](Symlinks::Follow).
+    pub fn metadata(&self) -> io::Result<Metadata> {
+        match self.options.symlinks {
+            Symlinks::Follow => fs::metadata(&self.path),
+            Symlinks::Keep | Symlinks::Skip => fs::symlink_metadata(&self.path),
+        }
+    }
+
+    /// Whether this node is a directory, and so may have children.
+    pub fn is_dir(&self) -> bool {
+        self.metadata().is_ok_and(|metadata| metadata.is_dir())
+    }
+
+    /// The entries of this node's directory, less any skipped or ignored, sorted by file name.
+    fn entries(&self) -> io::Result<Vec<Self>> {
+        if !self.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(&self.path)? {
+            let entry = entry?;
+
+            if self.options.symlinks == Symlinks::Skip && entry.file_type()?.is_symlink() {
+                continue;
+            }
+
+            let path = entry.path();
+            let relative = path.strip_prefix(&self.options.root).unwrap_or(&path);
+            if self.options.ignore.is_match(relative) {
+                continue;
+            }
+
+            entries.push(FsNode {
+                path,
+                options: self.options.clone(),
+            });
+        }
+
+        entries.sort_by(|a, b| a.path.file_name().cmp(&b.path.file_name()));
+        Ok(entries)
+    }
+}
+
+impl Zippable for FsNode {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.try_children().into_iter().flatten()
+    }
+
+    /// The directory containing this node.
+    fn parents(&self) -> impl Iterator<Item = Self> + '_ {
+        self.path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .map(|parent| FsNode {
+                path: parent.to_path_buf(),
+                options: self.options.clone(),
+            })
+            .into_iter()
+    }
+}
+
+impl TryZippable for FsNode {
+    type Error = io::Error;
+
+    fn try_children(&self) -> Result<impl Iterator<Item = Self> + '_, Self::Error> {
+        Ok(self.entries()?.into_iter())
+    }
+}
+
+impl Edged for FsNode {
+    /// The file name of the entry
+    type Edge = OsString;
+
+    fn edge(&self) -> Option<Self::Edge> {
+        self.name().map(OsStr::to_os_string)
+    }
+}
+
+impl Identified for FsNode {
+    /// The canonical path of the node, with links resolved, or its own path if that cannot be found
+    type Id = PathBuf;
+
+    fn id(&self) -> Self::Id {
+        fs::canonicalize(&self.path).unwrap_or_else(|_| self.path.clone())
+    }
+}
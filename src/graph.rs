@@ -0,0 +1,191 @@
+//! [Zippable] adapters for [petgraph] graphs. Requires the `petgraph` feature.
+//!
+//! Any of [Graph](petgraph::Graph), [StableGraph](petgraph::stable_graph::StableGraph) or
+//! [GraphMap](petgraph::graphmap::GraphMap) can be explored by wrapping a reference to it and a starting node
+//! in a [GraphNode]:
+//!
+//! ```
+//! use petgraph::Graph;
+//! use zippered::graph::{Edges, GraphNode};
+//! use zippered::zipper::*;
+//!
+//! # fn main() -> Result<(), ZipperErr> {
+//! let mut graph = Graph::<&str, ()>::new();
+//! let root = graph.add_node("root");
+//! let one = graph.add_node("one");
+//! let two = graph.add_node("two");
+//! graph.extend_with_edges([(root, one), (root, two)]);
+//!
+//! let node = GraphNode::new(&graph, root, Edges::Outgoing).zipper().down()?.right()?.node;
+//!
+//! assert_eq!(node.weight(), Some(&"two"));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::zipper::{Edged, Identified, Zippable};
+use petgraph::{
+    data::DataMap,
+    visit::{Data, EdgeIndexable, EdgeRef, GraphProp, IntoEdgesDirected},
+    Direction,
+};
+use std::hash::Hash;
+
+/// Which edges of a node lead to its children.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Edges {
+    /// Children are the targets of the node's outgoing edges
+    Outgoing,
+    /// Children are the sources of the node's incoming edges
+    Incoming,
+    /// Children are the nodes at the other end of any of the node's edges
+    Undirected,
+}
+
+/// A node of a petgraph graph, made [Zippable] by following its [Edges].
+///
+/// Children are ordered by the index of the edge leading to them, which is the order edges were added
+/// in unless edges have since been removed. A child is reached once per edge, so parallel edges lead to
+/// the same node more than once.
+#[derive(Debug)]
+pub struct GraphNode<G>
+where
+    G: IntoEdgesDirected,
+{
+    graph: G,
+    id: G::NodeId,
+    edges: Edges,
+    /// The edge this node was reached by, `None` for a node created by [GraphNode::new]
+    edge: Option<G::EdgeRef>,
+}
+
+impl<G> Clone for GraphNode<G>
+where
+    G: IntoEdgesDirected,
+{
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<G> Copy for GraphNode<G> where G: IntoEdgesDirected {}
+
+impl<G> GraphNode<G>
+where
+    G: IntoEdgesDirected,
+{
+    /// Creates a node of `graph` whose children are reached by following `edges`.
+    pub fn new(graph: G, id: G::NodeId, edges: Edges) -> Self {
+        Self {
+            graph,
+            id,
+            edges,
+            edge: None,
+        }
+    }
+
+    /// The graph this node belongs to.
+    pub fn graph(&self) -> G {
+        self.graph
+    }
+
+    /// The id of this node within its graph. For a [GraphMap](petgraph::graphmap::GraphMap), this is the node itself.
+    pub fn id(&self) -> G::NodeId {
+        self.id
+    }
+
+    /// Which edges are followed to reach this node's children.
+    pub fn edges(&self) -> Edges {
+        self.edges
+    }
+
+    /// The weight of this node, if its graph stores node weights.
+    pub fn weight(&self) -> Option<&G::NodeWeight>
+    where
+        G: Data + DataMap,
+    {
+        self.graph.node_weight(self.id)
+    }
+}
+
+impl<G> GraphNode<G>
+where
+    G: IntoEdgesDirected + EdgeIndexable + GraphProp,
+{
+    /// The nodes at the other end of this node's `edges`, each carrying the edge leading to it, ordered by edge index.
+    fn neighbors(&self, edges: Edges) -> impl Iterator<Item = Self> + '_ {
+        let directions = match (edges, self.graph.is_directed()) {
+            (Edges::Outgoing, _) => [Some(Direction::Outgoing), None],
+            (Edges::Incoming, _) => [Some(Direction::Incoming), None],
+            (Edges::Undirected, true) => [Some(Direction::Outgoing), Some(Direction::Incoming)],
+            // every edge of an undirected graph is already outgoing
+            (Edges::Undirected, false) => [Some(Direction::Outgoing), None],
+        };
+
+        let mut neighbors = directions
+            .into_iter()
+            .flatten()
+            .flat_map(|direction| self.graph.edges_directed(self.id, direction))
+            .map(|edge| {
+                let other = if edge.source() == self.id {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                (EdgeIndexable::to_index(&self.graph, edge.id()), edge, other)
+            })
+            .collect::<Vec<_>>();
+
+        // a self loop is both outgoing and incoming
+        neighbors.sort_by_key(|(index, _, _)| *index);
+        neighbors.dedup_by_key(|(index, _, _)| *index);
+
+        neighbors.into_iter().map(|(_, edge, id)| GraphNode {
+            id,
+            edge: Some(edge),
+            ..*self
+        })
+    }
+}
+
+impl<G> Zippable for GraphNode<G>
+where
+    G: IntoEdgesDirected + EdgeIndexable + GraphProp,
+{
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbors(self.edges)
+    }
+
+    /// The nodes at the other end of this node's edges in the opposite direction to its children.
+    fn parents(&self) -> impl Iterator<Item = Self> + '_ {
+        self.neighbors(match self.edges {
+            Edges::Outgoing => Edges::Incoming,
+            Edges::Incoming => Edges::Outgoing,
+            Edges::Undirected => Edges::Undirected,
+        })
+    }
+}
+
+impl<G> Edged for GraphNode<G>
+where
+    G: IntoEdgesDirected + EdgeIndexable + GraphProp,
+{
+    /// The petgraph edge reference, giving access to the edge's id and weight
+    type Edge = G::EdgeRef;
+
+    fn edge(&self) -> Option<Self::Edge> {
+        self.edge
+    }
+}
+
+impl<G> Identified for GraphNode<G>
+where
+    G: IntoEdgesDirected,
+    G::NodeId: Hash + Eq,
+{
+    type Id = G::NodeId;
+
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
@@ -0,0 +1,173 @@
+//! A [Zippable] view of [serde_json] documents. Requires the `json` feature.
+//!
+//! The children of a [JsonNode] are the elements of an array or the values of an object, in the object's iteration
+//! order. Each node knows the [Key] it was reached by and its [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901):
+//!
+//! ```
+//! use serde_json::json;
+//! use zippered::json::{JsonNode, Key};
+//! use zippered::zipper::*;
+//!
+//! # fn main() -> Result<(), ZipperErr> {
+//! let document = json!({ "users": [{ "name": "ada" }, { "name": "grace" }] });
+//! let root = JsonNode::new(&document);
+//!
+//! let name = root.zipper().down()?.down()?.right()?.down()?.node;
+//! assert_eq!(name.key(), Some(Key::Field("name")));
+//! assert_eq!(name.pointer(), "/users/1/name");
+//!
+//! // and back again
+//! let steps = root.steps_to("/users/1/name").expect("an existing value");
+//! assert_eq!(root.zipper().travel(steps.into_iter())?.node.value(), "grace");
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::zipper::{Edged, Step, Zippable};
+use serde_json::Value;
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+/// How a [JsonNode] is reached from its parent.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Key<'a> {
+    /// The position of an element of an array
+    Index(usize),
+    /// The name of a value of an object
+    Field(&'a str),
+}
+
+impl Display for Key<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Index(index) => write!(f, "{index}"),
+            Key::Field(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+/// A value within a JSON document, made [Zippable] by its array elements or object values.
+#[derive(Debug, Clone)]
+pub struct JsonNode<'a> {
+    value: &'a Value,
+    key: Option<Key<'a>>,
+    parent: Option<Arc<JsonNode<'a>>>,
+}
+
+impl<'a> JsonNode<'a> {
+    /// Creates a node for the root of a document.
+    pub fn new(value: &'a Value) -> Self {
+        Self {
+            value,
+            key: None,
+            parent: None,
+        }
+    }
+
+    /// The value of this node.
+    pub fn value(&self) -> &'a Value {
+        self.value
+    }
+
+    /// The object field or array index this node was reached by, or `None` at the root.
+    pub fn key(&self) -> Option<Key<'a>> {
+        self.key
+    }
+
+    /// The JSON Pointer to this node from the root of its document, e.g. `/users/0/name`. The root is `""`.
+    pub fn pointer(&self) -> String {
+        let keys = std::iter::successors(Some(self), |node| node.parent.as_deref())
+            .filter_map(|node| node.key)
+            .collect::<Vec<_>>();
+
+        keys.iter().rev().fold(String::new(), |mut pointer, key| {
+            pointer.push('/');
+            match key {
+                Key::Index(index) => pointer.push_str(&index.to_string()),
+                Key::Field(name) => pointer.push_str(&name.replace('~', "~0").replace('/', "~1")),
+            }
+            pointer
+        })
+    }
+
+    /// The [Step]s from this node to the value at `pointer`, taken relative to this node, or `None` if `pointer`
+    /// is malformed or leads nowhere. Travelling these steps from a [Zipper](crate::zipper::Zipper) positioned at
+    /// this node arrives at that value.
+    pub fn steps_to(&self, pointer: &str) -> Option<Vec<Step>> {
+        if pointer.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let mut steps = Vec::new();
+        let mut value = self.value;
+        for token in pointer.strip_prefix('/')?.split('/') {
+            let token = token.replace("~1", "/").replace("~0", "~");
+
+            let index = match value {
+                Value::Array(array) => {
+                    let leading_zero = token.len() > 1 && token.starts_with('0');
+                    if leading_zero || !token.bytes().all(|byte| byte.is_ascii_digit()) {
+                        return None;
+                    }
+                    let index = token.parse::<usize>().ok()?;
+                    value = array.get(index)?;
+                    index
+                }
+                Value::Object(object) => {
+                    let index = object.keys().position(|name| *name == token)?;
+                    value = &object[&token];
+                    index
+                }
+                _ => return None,
+            };
+
+            steps.push(Step::Down);
+            steps.extend(std::iter::repeat_n(Step::Right, index));
+        }
+
+        Some(steps)
+    }
+
+    /// The children of this node along with the key leading to each.
+    fn entries(&self) -> impl Iterator<Item = (Key<'a>, Self)> + '_ {
+        let parent = Arc::new(self.clone());
+        let child = move |key, value| {
+            let child = JsonNode {
+                value,
+                key: Some(key),
+                parent: Some(parent.clone()),
+            };
+            (key, child)
+        };
+
+        let keys: Box<dyn Iterator<Item = (Key<'a>, &'a Value)>> = match self.value {
+            Value::Array(array) => Box::new(
+                array
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| (Key::Index(index), value)),
+            ),
+            Value::Object(object) => {
+                Box::new(object.iter().map(|(name, value)| (Key::Field(name), value)))
+            }
+            _ => Box::new(std::iter::empty()),
+        };
+
+        keys.map(move |(key, value)| child(key, value))
+    }
+}
+
+impl Zippable for JsonNode<'_> {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.entries().map(|(_, child)| child)
+    }
+}
+
+impl<'a> Edged for JsonNode<'a> {
+    /// The object field or array index of the child
+    type Edge = Key<'a>;
+
+    fn edge(&self) -> Option<Self::Edge> {
+        self.key
+    }
+}
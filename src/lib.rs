@@ -1,3 +1,27 @@
 #![doc = include_str!("../README.md")]
 
+pub mod arena;
+pub mod render;
+pub mod similarity;
 pub mod zipper;
+
+#[cfg(feature = "async")]
+pub mod async_zipper;
+
+#[cfg(feature = "fs")]
+pub mod fs;
+
+#[cfg(feature = "json")]
+pub mod json;
+
+#[cfg(any(feature = "xml", feature = "html"))]
+pub mod markup;
+
+#[cfg(feature = "petgraph")]
+pub mod graph;
+
+#[cfg(feature = "rayon")]
+mod parallel;
+
+#[cfg(feature = "tree-sitter")]
+pub mod syntax;
@@ -0,0 +1,189 @@
+//! [Zippable](crate::zipper::Zippable) views of parsed XML and HTML documents. Requires the `xml` feature for
+//! [XmlNode], via [roxmltree], and the `html` feature for [HtmlNode], via [scraper].
+//!
+//! Both start at the root element of a document and give access to the element name, attributes and text of
+//! the focus. Their children are either the child elements alone or also the text between them, see [Content].
+//!
+//! ```
+//! # #[cfg(feature = "xml")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use zippered::markup::{Content, XmlNode};
+//! use zippered::zipper::{Step::*, *};
+//!
+//! let document = roxmltree::Document::parse(r#"<list><item id="a">one</item><item id="b">two</item></list>"#)?;
+//!
+//! let item = XmlNode::new(&document, Content::Elements)
+//!     .zipper()
+//!     .travel([Down, Right].into_iter())?
+//!     .node;
+//!
+//! assert_eq!(item.name(), Some("item"));
+//! assert_eq!(item.attribute("id"), Some("b"));
+//! assert_eq!(item.text(), "two");
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "xml"))]
+//! # fn main() {}
+//! ```
+
+/// Which of a markup node's children are visited.
+#[derive(Debug, Copy, Clone, Default, Hash, PartialEq, Eq)]
+pub enum Content {
+    /// Child elements only
+    #[default]
+    Elements,
+    /// Child elements and text nodes, including whitespace between elements
+    Text,
+}
+
+#[cfg(feature = "xml")]
+pub use xml::XmlNode;
+
+#[cfg(feature = "html")]
+pub use html::HtmlNode;
+
+#[cfg(feature = "xml")]
+mod xml {
+    use super::Content;
+    use crate::zipper::Zippable;
+    use roxmltree::{Document, Node};
+
+    /// A node of a parsed XML document, made [Zippable] by its element and optionally text children.
+    #[derive(Debug, Copy, Clone)]
+    pub struct XmlNode<'a, 'input> {
+        node: Node<'a, 'input>,
+        content: Content,
+    }
+
+    impl<'a, 'input> XmlNode<'a, 'input> {
+        /// Creates a node for the root element of `document`, visiting children according to `content`.
+        pub fn new(document: &'a Document<'input>, content: Content) -> Self {
+            Self {
+                node: document.root_element(),
+                content,
+            }
+        }
+
+        /// The underlying roxmltree node.
+        pub fn node(&self) -> Node<'a, 'input> {
+            self.node
+        }
+
+        /// Whether this node is an element rather than text.
+        pub fn is_element(&self) -> bool {
+            self.node.is_element()
+        }
+
+        /// The local name of this element, or `None` for text.
+        pub fn name(&self) -> Option<&'input str> {
+            self.is_element().then(|| self.node.tag_name().name())
+        }
+
+        /// The value of the attribute of this element called `name`.
+        pub fn attribute(&self, name: &str) -> Option<&'a str> {
+            self.node.attribute(name)
+        }
+
+        /// The names and values of the attributes of this element, in document order.
+        pub fn attributes(&self) -> impl Iterator<Item = (&'input str, &'a str)> {
+            self.node
+                .attributes()
+                .map(|attribute| (attribute.name(), attribute.value()))
+        }
+
+        /// The text of this text node, or all of the text within this element.
+        pub fn text(&self) -> String {
+            self.node
+                .descendants()
+                .filter(|node| node.is_text())
+                .filter_map(|node| node.text())
+                .collect()
+        }
+    }
+
+    impl Zippable for XmlNode<'_, '_> {
+        fn children(&self) -> impl Iterator<Item = Self> + '_ {
+            self.node
+                .children()
+                .filter(|node| match self.content {
+                    Content::Elements => node.is_element(),
+                    Content::Text => node.is_element() || node.is_text(),
+                })
+                .map(|node| XmlNode { node, ..*self })
+        }
+    }
+}
+
+#[cfg(feature = "html")]
+mod html {
+    use super::Content;
+    use crate::zipper::Zippable;
+    use ego_tree::NodeRef;
+    use scraper::{Html, Node};
+
+    /// A node of a parsed HTML document, made [Zippable] by its element and optionally text children.
+    #[derive(Debug, Copy, Clone)]
+    pub struct HtmlNode<'a> {
+        node: NodeRef<'a, Node>,
+        content: Content,
+    }
+
+    impl<'a> HtmlNode<'a> {
+        /// Creates a node for the root element of `html`, visiting children according to `content`.
+        pub fn new(html: &'a Html, content: Content) -> Self {
+            Self {
+                node: *html.root_element(),
+                content,
+            }
+        }
+
+        /// The underlying scraper node.
+        pub fn node(&self) -> NodeRef<'a, Node> {
+            self.node
+        }
+
+        /// Whether this node is an element rather than text.
+        pub fn is_element(&self) -> bool {
+            self.node.value().is_element()
+        }
+
+        /// The local name of this element, or `None` for text.
+        pub fn name(&self) -> Option<&'a str> {
+            self.node.value().as_element().map(|element| element.name())
+        }
+
+        /// The value of the attribute of this element called `name`.
+        pub fn attribute(&self, name: &str) -> Option<&'a str> {
+            self.node.value().as_element()?.attr(name)
+        }
+
+        /// The names and values of the attributes of this element.
+        pub fn attributes(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+            self.node
+                .value()
+                .as_element()
+                .into_iter()
+                .flat_map(|element| element.attrs())
+        }
+
+        /// The text of this text node, or all of the text within this element.
+        pub fn text(&self) -> String {
+            self.node
+                .descendants()
+                .filter_map(|node| node.value().as_text().map(|text| &**text))
+                .collect()
+        }
+    }
+
+    impl Zippable for HtmlNode<'_> {
+        fn children(&self) -> impl Iterator<Item = Self> + '_ {
+            self.node
+                .children()
+                .filter(|node| match self.content {
+                    Content::Elements => node.value().is_element(),
+                    Content::Text => node.value().is_element() || node.value().is_text(),
+                })
+                .map(|node| HtmlNode { node, ..*self })
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Parallel search and aggregation below a [Zipper]'s position with [rayon]. Requires the `rayon` feature.
+//!
+//! A [Zipper] is built on [Rc](std::rc::Rc) and so stays on one thread; instead each descendant is visited as a
+//! `(path, node)` pair, where the path leads from the root of the [Zipper] just like [Zipper::path] does.
+
+use crate::zipper::{CyclePolicy, Step, Zippable, Zipper};
+use rayon::iter::{walk_tree_prefix, ParallelIterator};
+use std::sync::Arc;
+
+/// A node along with the path leading to it from the root.
+type Visit<T> = (Vec<Step>, T);
+
+/// An ancestor of a node being visited, linked to its own parent so that siblings share their ancestors.
+struct Ancestor<T> {
+    node: T,
+    /// The hash of the node's id, if cycles are detected
+    hash: Option<u64>,
+    parent: Option<Arc<Ancestor<T>>>,
+}
+
+/// A node being visited, along with its ancestors and whether it closes a cycle.
+struct Item<T> {
+    visit: Visit<T>,
+    /// The hash of the node's id, if cycles are detected
+    hash: Option<u64>,
+    parent: Option<Arc<Ancestor<T>>>,
+    cycle: bool,
+}
+
+impl<T> Item<T> {
+    /// The ancestors of the node, from its parent upwards.
+    fn ancestors(&self) -> impl Iterator<Item = &Ancestor<T>> {
+        std::iter::successors(self.parent.as_deref(), |ancestor| {
+            ancestor.parent.as_deref()
+        })
+    }
+}
+
+impl<T> Zipper<T>
+where
+    T: Zippable + Send + Sync,
+{
+    /// Returns a [ParallelIterator] over every descendant of the current value along with its path, with the
+    /// children of each value split across threads. Collecting it gives depth-first pre-order, as
+    /// [descendants](#method.descendants) does.
+    ///
+    /// Children are listed afresh via [Zippable::children] rather than through the session, so its cache does not
+    /// apply. Its [CyclePolicy] does, as for [descendants](#method.descendants): under [CyclePolicy::Error] nodes
+    /// that close a cycle are skipped, and under [CyclePolicy::Leaf] they are visited but not descended into.
+    pub fn par_descendants(&self) -> impl ParallelIterator<Item = Visit<T>> {
+        let cycles = self
+            .shared
+            .cycles
+            .as_ref()
+            .map(|cycles| (cycles.policy, cycles.hash, cycles.same));
+
+        // the ancestors of the current value, linked from the root down
+        let parent =
+            self.ancestors()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .fold(None, |parent, frame| {
+                    Some(Arc::new(Ancestor {
+                        node: frame.node.clone(),
+                        hash: frame.hash,
+                        parent,
+                    }))
+                });
+        let root = Item {
+            visit: (self.path().collect(), self.node.clone()),
+            hash: self.hash,
+            parent,
+            cycle: self.cycle().is_some(),
+        };
+        let depth = root.visit.0.len();
+
+        walk_tree_prefix(root, move |item: &Item<T>| {
+            // never continue a cycle
+            if item.cycle {
+                return Vec::new();
+            }
+
+            let (path, node) = &item.visit;
+            let parent = Some(Arc::new(Ancestor {
+                node: node.clone(),
+                hash: item.hash,
+                parent: item.parent.clone(),
+            }));
+
+            node.children()
+                .enumerate()
+                .map(|(index, child)| {
+                    let mut path = path.clone();
+                    path.push(Step::Down);
+                    path.extend(std::iter::repeat_n(Step::Right, index));
+
+                    let mut child = Item {
+                        hash: cycles.map(|(_, hash, _)| hash(&child)),
+                        visit: (path, child),
+                        parent: parent.clone(),
+                        cycle: false,
+                    };
+                    // only ancestors whose id hashes the same have their ids compared
+                    child.cycle = cycles.is_some_and(|(_, _, same)| {
+                        child.ancestors().any(|ancestor| {
+                            ancestor.hash == child.hash && same(&child.visit.1, &ancestor.node)
+                        })
+                    });
+                    child
+                })
+                .collect::<Vec<_>>()
+        })
+        // leave out the current value itself, and under CyclePolicy::Error any value that closes a cycle
+        .filter(move |item| {
+            item.visit.0.len() > depth
+                && !(item.cycle
+                    && cycles.is_some_and(|(policy, _, _)| policy == CyclePolicy::Error))
+        })
+        .map(|item| item.visit)
+    }
+
+    /// Returns the first descendant of the current value in depth-first pre-order, along with its path, for which
+    /// `predicate` holds, searching subtrees in parallel. See [par_descendants](#method.par_descendants).
+    pub fn par_find<P>(&self, predicate: P) -> Option<Visit<T>>
+    where
+        P: Fn(&T) -> bool + Send + Sync,
+    {
+        self.par_descendants()
+            .find_first(|(_, node)| predicate(node))
+    }
+
+    /// Aggregates every descendant of the current value and its path, folding subtrees in parallel into
+    /// accumulators that start from `identity` and then combining them with `reduce`. See
+    /// [par_descendants](#method.par_descendants).
+    pub fn par_fold<A, I, F, R>(&self, identity: I, fold: F, reduce: R) -> A
+    where
+        A: Send,
+        I: Fn() -> A + Send + Sync,
+        F: Fn(A, &[Step], &T) -> A + Send + Sync,
+        R: Fn(A, A) -> A + Send + Sync,
+    {
+        self.par_descendants()
+            .fold(&identity, |acc, (path, node)| fold(acc, &path, &node))
+            .reduce(&identity, reduce)
+    }
+}
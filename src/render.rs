@@ -0,0 +1,308 @@
+//! Text renderings of the tree a [Zipper] has explored, with its current position highlighted: as an indented tree
+//! with [Zipper::render], or as a Graphviz graph of the journey taken with [Zipper::dot].
+//!
+//! Only positions already visited by the [Zipper]'s session are drawn by default, so rendering never lists the
+//! children of a value; [expand](Render::expand) lists them regardless.
+//!
+//! ```
+//! use zippered::zipper::*;
+//!
+//! let zipper = from_fn(1, |&n: &usize| if n < 4 { vec![n * 2, n * 2 + 1] } else { vec![] })
+//!     .down()?
+//!     .right()?
+//!     .down()?
+//!     .right()?
+//!     .left()?;
+//!
+//! assert_eq!(
+//!     zipper.render(|node| node.to_string()).to_string(),
+//!     "\
+//! 1 (ancestor)
+//! ├── 2
+//! └── 3 (ancestor)
+//!     ├── 6 (focus)
+//!     └── 7 (sibling)"
+//! );
+//! # Ok::<(), ZipperErr>(())
+//! ```
+
+use crate::zipper::{parent_path, History, Path, Step, Zippable, Zipper};
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+/// A rendering of the tree explored by a [Zipper], as an indented tree drawn with box-drawing characters. Each
+/// value is labelled by a user supplied function, and the focus, its ancestors and its siblings are marked as
+/// such. See [Zipper::render].
+pub struct Render<'a, T, L>
+where
+    T: Zippable,
+{
+    zipper: &'a Zipper<T>,
+    label: L,
+    levels: Option<usize>,
+    expand: bool,
+}
+
+impl<T> Zipper<T>
+where
+    T: Zippable,
+{
+    /// Returns a [Render] of the tree explored so far, labelling each value with `label`. Its [Display]
+    /// implementation draws the tree.
+    pub fn render<L>(&self, label: L) -> Render<'_, T, L>
+    where
+        L: Fn(&T) -> String,
+    {
+        Render {
+            zipper: self,
+            label,
+            levels: None,
+            expand: false,
+        }
+    }
+}
+
+impl<T, L> Render<'_, T, L>
+where
+    T: Zippable,
+    L: Fn(&T) -> String,
+{
+    /// Limits the rendering to the neighbourhood of the focus: starting from its ancestor `levels` levels up, and
+    /// going no further than `levels` levels below it.
+    pub fn around(mut self, levels: usize) -> Self {
+        self.levels = Some(levels);
+        self
+    }
+
+    /// Lists the children of every value drawn, rather than only those already visited. The children are
+    /// listed through the session, so its children cache applies, but a tree of unbounded depth should be
+    /// limited with [around](#method.around).
+    pub fn expand(mut self) -> Self {
+        self.expand = true;
+        self
+    }
+
+    /// How the value at `path` relates to the focus.
+    fn mark(&self, path: &Path) -> &'static str {
+        let focus = &self.zipper.history.path;
+
+        if path == focus {
+            " (focus)"
+        } else if focus.get(path.len()) == Some(&Step::Down)
+            && focus.iter().take(path.len()).eq(path)
+        {
+            " (ancestor)"
+        } else if !path.is_empty() && !focus.is_empty() && parent_path(path) == parent_path(focus) {
+            " (sibling)"
+        } else {
+            ""
+        }
+    }
+
+    /// The children of the value `node` at `path`, either visited or listed, along with their paths.
+    fn children(&self, node: &T, path: &Path) -> Vec<(Path, T)> {
+        if !self.expand {
+            return visited(self.zipper, path);
+        }
+
+        let children = self.zipper.shared.children(node).unwrap_or_default();
+        children
+            .into_iter()
+            .enumerate()
+            .map(|(index, child)| (child_path(path, index), child))
+            .collect()
+    }
+
+    /// Draws the descendants of the value `node` at `path`, `depth` levels below the root, each line starting
+    /// with `indent`.
+    fn write_children(
+        &self,
+        f: &mut Formatter<'_>,
+        node: &T,
+        path: &Path,
+        depth: usize,
+        indent: &str,
+    ) -> fmt::Result {
+        let limit = self
+            .levels
+            .map(|levels| depth_of(&self.zipper.history.path) + levels);
+        if limit.is_some_and(|limit| depth >= limit) {
+            return Ok(());
+        }
+
+        let children = self.children(node, path);
+        let count = children.len();
+
+        for (index, (path, child)) in children.into_iter().enumerate() {
+            let (branch, extension) = match index + 1 == count {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+
+            write!(
+                f,
+                "\n{indent}{branch}{}{}",
+                (self.label)(&child),
+                self.mark(&path)
+            )?;
+            self.write_children(f, &child, &path, depth + 1, &format!("{indent}{extension}"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, L> Display for Render<'_, T, L>
+where
+    T: Zippable,
+    L: Fn(&T) -> String,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (mut node, mut path) = (&self.zipper.node, self.zipper.history.path.clone());
+        for frame in self
+            .zipper
+            .ancestors()
+            .take(self.levels.unwrap_or(usize::MAX))
+        {
+            (node, path) = (&frame.node, parent_path(&path));
+        }
+
+        write!(f, "{}{}", (self.label)(node), self.mark(&path))?;
+        self.write_children(f, node, &path, depth_of(&path), "")
+    }
+}
+
+/// A rendering of the tree explored by a [Zipper] in the [Graphviz](https://graphviz.org) DOT language. Each
+/// visited value is labelled by a user supplied function, the focus is highlighted, and the moves of the
+/// [journey](Zipper::journey) are drawn as edges numbered in the order they were taken. See [Zipper::dot].
+///
+/// Only the positions below the current root are drawn, i.e. those reached since the latest [Step::UpTo].
+pub struct Dot<'a, T, L>
+where
+    T: Zippable,
+{
+    zipper: &'a Zipper<T>,
+    label: L,
+}
+
+impl<T> Zipper<T>
+where
+    T: Zippable,
+{
+    /// Returns a [Dot] of every position visited so far, labelling each value with `label`. Its [Display]
+    /// implementation writes the graph.
+    pub fn dot<L>(&self, label: L) -> Dot<'_, T, L>
+    where
+        L: Fn(&T) -> String,
+    {
+        Dot {
+            zipper: self,
+            label,
+        }
+    }
+}
+
+impl<T, L> Display for Dot<'_, T, L>
+where
+    T: Zippable,
+    L: Fn(&T) -> String,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let root = self
+            .zipper
+            .ancestors()
+            .last()
+            .map_or(&self.zipper.node, |frame| &frame.node);
+
+        // number every visited position in depth-first pre-order
+        let mut ids = HashMap::new();
+        let mut stack = vec![(Path::new(), root.clone(), None)];
+
+        writeln!(f, "digraph zipper {{")?;
+        writeln!(f, "    node [shape=box];")?;
+
+        while let Some((path, node, parent)) = stack.pop() {
+            let id = ids.len();
+            let focus = match path == self.zipper.history.path {
+                true => ", style=filled, fillcolor=gold",
+                false => "",
+            };
+            writeln!(
+                f,
+                "    {id} [label=\"{}\"{focus}];",
+                escape(&(self.label)(&node))
+            )?;
+            if let Some(parent) = parent {
+                writeln!(f, "    {parent} -> {id} [color=gray, arrowhead=none];")?;
+            }
+
+            let children = visited(self.zipper, &path);
+            stack.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .map(|(path, node)| (path, node, Some(id))),
+            );
+            ids.insert(path, id);
+        }
+
+        // replay the journey since the latest change of root
+        let journey = self.zipper.journey().collect::<Vec<_>>();
+        let start = journey
+            .iter()
+            .rposition(|step| matches!(step, Step::UpTo(_)))
+            .map_or(0, |index| index + 1);
+        let mut history = History::new(self.zipper.history.root_id);
+
+        for (number, step) in journey[start..].iter().enumerate() {
+            let from = ids.get(&history.path);
+            history = history.step(*step);
+
+            if let (Some(from), Some(to)) = (from, ids.get(&history.path)) {
+                writeln!(
+                    f,
+                    "    {from} -> {to} [label=\"{} {step:?}\", color=red, fontcolor=red, constraint=false];",
+                    number + 1
+                )?;
+            }
+        }
+
+        write!(f, "}}")
+    }
+}
+
+/// `label` as the contents of a DOT string.
+fn escape(label: &str) -> String {
+    label
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// The children of the value at `path` that have been visited by `zipper`'s session, along with their paths.
+fn visited<T>(zipper: &Zipper<T>, path: &Path) -> Vec<(Path, T)>
+where
+    T: Zippable,
+{
+    let root_id = zipper.history.root_id;
+    (0..)
+        .map(|index| child_path(path, index))
+        .map_while(|path| {
+            let frame = zipper.shared.cache.get(&(root_id, path.clone()))?;
+            Some((path, frame.node))
+        })
+        .collect()
+}
+
+/// The path of the `index`th child of the value at `path`.
+fn child_path(path: &Path, index: usize) -> Path {
+    let mut path = path.clone();
+    path.push_back(Step::Down);
+    path.extend(std::iter::repeat_n(Step::Right, index));
+    path
+}
+
+/// The number of levels below the root of the value at `path`.
+fn depth_of(path: &Path) -> usize {
+    path.iter().filter(|step| **step == Step::Down).count()
+}
@@ -0,0 +1,229 @@
+//! Measures of how alike the subtrees below two [Zipper]s are.
+//!
+//! Each measure compares the current value of each [Zipper] and its descendants, down to an optional `depth`
+//! below the current position so that large or deep graphs stay tractable. Descendants are found through
+//! [Zipper::descendants], so the session's cache and [CyclePolicy](crate::zipper::CyclePolicy) apply.
+//!
+//! ```
+//! use zippered::similarity::edit_distance;
+//! use zippered::zipper::*;
+//!
+//! #[derive(Clone)]
+//! enum Tree {
+//!     Node(usize),
+//!     Branch(Vec<Tree>),
+//! }
+//!
+//! impl Zippable for Tree {
+//!     fn children(&self) -> Box<dyn Iterator<Item = Self> + '_> {
+//!         match self {
+//!             Tree::Node(_) => Box::new(std::iter::empty()),
+//!             Tree::Branch(branch) => Box::new(branch.iter().cloned()),
+//!         }
+//!     }
+//! }
+//!
+//! let one = Tree::Branch(vec![Tree::Node(1), Tree::Node(2)]);
+//! let two = Tree::Branch(vec![Tree::Node(1), Tree::Node(3)]);
+//!
+//! // compare the labels of nodes, not the whole subtrees below them
+//! let same = |a: &Tree, b: &Tree| match (a, b) {
+//!     (Tree::Node(a), Tree::Node(b)) => a == b,
+//!     (Tree::Branch(_), Tree::Branch(_)) => true,
+//!     _ => false,
+//! };
+//!
+//! assert_eq!(edit_distance(&one.zipper(), &two.zipper(), None, same), 1);
+//! ```
+
+use crate::zipper::{Step, Zippable, Zipper};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// The Zhang–Shasha tree edit distance between the subtrees below `a` and `b`: the fewest node insertions,
+/// deletions and relabellings turning one into the other, each costing `1`.
+///
+/// Relabelling is free for nodes that are the `same`, so comparing only part of a node (e.g. a label and not
+/// its children) gives the usual labelled-tree distance.
+#[allow(clippy::needless_range_loop)]
+pub fn edit_distance<T, F>(a: &Zipper<T>, b: &Zipper<T>, depth: Option<usize>, same: F) -> usize
+where
+    T: Zippable,
+    F: Fn(&T, &T) -> bool,
+{
+    let (a, b) = (subtree(a, depth), subtree(b, depth));
+    let (a, b) = (Postorder::new(&a), Postorder::new(&b));
+
+    let mut tree = vec![vec![0; b.nodes.len()]; a.nodes.len()];
+
+    for &i in &a.keyroots {
+        for &j in &b.keyroots {
+            let (li, lj) = (a.leftmost[i], b.leftmost[j]);
+            let mut forest = vec![vec![0; j - lj + 2]; i - li + 2];
+
+            for (x, row) in forest.iter_mut().enumerate() {
+                row[0] = x;
+            }
+            for (y, cell) in forest[0].iter_mut().enumerate() {
+                *cell = y;
+            }
+
+            for di in li..=i {
+                for dj in lj..=j {
+                    let (x, y) = (di - li + 1, dj - lj + 1);
+                    let edit = (forest[x - 1][y] + 1).min(forest[x][y - 1] + 1);
+
+                    if a.leftmost[di] == li && b.leftmost[dj] == lj {
+                        let relabel = usize::from(!same(a.nodes[di], b.nodes[dj]));
+                        forest[x][y] = edit.min(forest[x - 1][y - 1] + relabel);
+                        tree[di][dj] = forest[x][y];
+                    } else {
+                        let (p, q) = (a.leftmost[di] - li, b.leftmost[dj] - lj);
+                        forest[x][y] = edit.min(forest[p][q] + tree[di][dj]);
+                    }
+                }
+            }
+        }
+    }
+
+    tree[a.nodes.len() - 1][b.nodes.len() - 1]
+}
+
+/// The proportion of paths below `a` and `b` that lead to the `same` node in both, out of every path found
+/// below either. Identical subtrees give `1.0`, and subtrees without even a matching current value give `0.0`.
+///
+/// Cheaper than [edit_distance], but a single insertion shifts every later sibling and so counts against them.
+pub fn path_overlap<T, F>(a: &Zipper<T>, b: &Zipper<T>, depth: Option<usize>, same: F) -> f64
+where
+    T: Zippable,
+    F: Fn(&T, &T) -> bool,
+{
+    let a = paths(a, depth);
+    let b = paths(b, depth);
+
+    let (mut both, mut matching) = (0, 0);
+    for (path, node) in &b {
+        if let Some(other) = a.get(path) {
+            both += 1;
+            matching += usize::from(same(&other.node, &node.node));
+        }
+    }
+
+    matching as f64 / (a.len() + b.len() - both) as f64
+}
+
+/// The Jaccard index of the descendants of `a` and `b`, compared by `key` regardless of where they appear:
+/// the number of keys found below both over the number found below either.
+///
+/// The current values themselves are not included. Two leaves give `1.0`.
+pub fn descendant_jaccard<T, K, F>(
+    a: &Zipper<T>,
+    b: &Zipper<T>,
+    depth: Option<usize>,
+    key: F,
+) -> f64
+where
+    T: Zippable,
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    let keys = |zipper: &Zipper<T>| -> HashSet<K> {
+        subtree(zipper, depth)
+            .iter()
+            .skip(1)
+            .map(|(_, descendant)| key(&descendant.node))
+            .collect()
+    };
+
+    let a = keys(a);
+    let b = keys(b);
+
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+/// The current position followed by its descendants in pre-order, each with its depth below the current position.
+fn subtree<T>(zipper: &Zipper<T>, depth: Option<usize>) -> Vec<(usize, Zipper<T>)>
+where
+    T: Zippable,
+{
+    let descendants = match depth {
+        Some(depth) => zipper.descendants().max_depth(depth),
+        None => zipper.descendants(),
+    };
+
+    let base = zipper.depth();
+    std::iter::once(zipper.clone())
+        .chain(descendants)
+        .map(|descendant| (descendant.depth() - base, descendant))
+        .collect()
+}
+
+/// Each node of a subtree keyed by its path from the current position.
+fn paths<T>(zipper: &Zipper<T>, depth: Option<usize>) -> HashMap<Vec<Step>, Zipper<T>>
+where
+    T: Zippable,
+{
+    let base = zipper.path().count();
+    subtree(zipper, depth)
+        .into_iter()
+        .map(|(_, descendant)| (descendant.path().skip(base).collect(), descendant))
+        .collect()
+}
+
+/// A subtree numbered in post-order, as Zhang–Shasha expects.
+struct Postorder<'a, T> {
+    nodes: Vec<&'a T>,
+    /// The post-order number of the leftmost leaf below each node
+    leftmost: Vec<usize>,
+    /// Nodes that are the root or have a left sibling, in ascending order
+    keyroots: Vec<usize>,
+}
+
+impl<'a, T> Postorder<'a, T>
+where
+    T: Zippable,
+{
+    fn new(preorder: &'a [(usize, Zipper<T>)]) -> Self {
+        let mut nodes = Vec::with_capacity(preorder.len());
+        let mut leftmost = Vec::with_capacity(preorder.len());
+
+        // each open node with the leftmost leaf below it, once its first child has closed
+        let mut open: Vec<(usize, &T, Option<usize>)> = Vec::new();
+        let mut close = |open: &mut Vec<(usize, &'a T, Option<usize>)>| {
+            let (_, node, leaf) = open.pop()?;
+            let leaf = leaf.unwrap_or(nodes.len());
+            nodes.push(node);
+            leftmost.push(leaf);
+            if let Some((_, _, parent @ None)) = open.last_mut() {
+                *parent = Some(leaf);
+            }
+            Some(())
+        };
+
+        for (depth, zipper) in preorder {
+            while open.last().is_some_and(|(open, _, _)| open >= depth) {
+                close(&mut open);
+            }
+            open.push((*depth, &zipper.node, None));
+        }
+        while close(&mut open).is_some() {}
+
+        let mut seen = HashSet::new();
+        let mut keyroots: Vec<usize> = (0..nodes.len())
+            .rev()
+            .filter(|&i| seen.insert(leftmost[i]))
+            .collect();
+        keyroots.reverse();
+
+        Self {
+            nodes,
+            leftmost,
+            keyroots,
+        }
+    }
+}
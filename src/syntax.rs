@@ -0,0 +1,123 @@
+//! A [Zippable] view of [tree_sitter] syntax trees. Requires the `tree-sitter` feature.
+//!
+//! Unlike tree-sitter's own [TreeCursor](tree_sitter::TreeCursor), a [Zipper](crate::zipper::Zipper) over
+//! [SyntaxNode]s can be cloned freely and moved [back](crate::zipper::Zipper::back) along its
+//! [journey](crate::zipper::Zipper::journey):
+//!
+//! ```
+//! use tree_sitter::Parser;
+//! use zippered::syntax::{Children, SyntaxNode};
+//! use zippered::zipper::{Step::*, *};
+//!
+//! # fn main() -> Result<(), ZipperErr> {
+//! let source = r#"{ "key": [1, 2] }"#;
+//! let mut parser = Parser::new();
+//! parser.set_language(&tree_sitter_json::LANGUAGE.into()).expect("a compatible grammar");
+//! let tree = parser.parse(source, None).expect("a parse");
+//!
+//! let pair = SyntaxNode::new(tree.root_node(), Children::Named)
+//!     .zipper()
+//!     .travel([Down, Down].into_iter())?;
+//! assert_eq!(pair.node.kind(), "pair");
+//!
+//! let value = pair.down()?.right()?.node;
+//! assert_eq!(value.field(), Some("value"));
+//! assert_eq!(value.text(source.as_bytes()), Some("[1, 2]"));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::zipper::{Identified, Zippable};
+use std::ops::Range;
+use tree_sitter::Node;
+
+/// Which of a syntax node's children are visited.
+#[derive(Debug, Copy, Clone, Default, Hash, PartialEq, Eq)]
+pub enum Children {
+    /// Named nodes only, i.e. those with a rule of their own in the grammar
+    #[default]
+    Named,
+    /// Every node, including anonymous ones such as punctuation and keywords
+    All,
+}
+
+/// A node of a tree-sitter syntax tree, made [Zippable] by its named or all children.
+#[derive(Debug, Copy, Clone)]
+pub struct SyntaxNode<'tree> {
+    node: Node<'tree>,
+    field: Option<&'tree str>,
+    children: Children,
+}
+
+impl<'tree> SyntaxNode<'tree> {
+    /// Creates a node for `node`, typically the root node of a tree, visiting the given `children`.
+    pub fn new(node: Node<'tree>, children: Children) -> Self {
+        Self {
+            node,
+            field: None,
+            children,
+        }
+    }
+
+    /// The underlying tree-sitter node.
+    pub fn node(&self) -> Node<'tree> {
+        self.node
+    }
+
+    /// The grammar's name for the kind of this node, e.g. `object` or `"{"`.
+    pub fn kind(&self) -> &'tree str {
+        self.node.kind()
+    }
+
+    /// The range of bytes of the source text spanned by this node.
+    pub fn byte_range(&self) -> Range<usize> {
+        self.node.byte_range()
+    }
+
+    /// The name of the field of its parent this node was reached by, or `None` at the starting node or for
+    /// nodes that are not in a field.
+    pub fn field(&self) -> Option<&'tree str> {
+        self.field
+    }
+
+    /// The text spanned by this node within `source`, the text the tree was parsed from, if it is valid UTF-8.
+    pub fn text<'s>(&self, source: &'s [u8]) -> Option<&'s str> {
+        self.node.utf8_text(source).ok()
+    }
+}
+
+impl Zippable for SyntaxNode<'_> {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        let mut children = Vec::new();
+
+        // a cursor finds field names without searching the parent's children for each child
+        let mut cursor = self.node.walk();
+        if cursor.goto_first_child() {
+            loop {
+                let node = cursor.node();
+                if self.children == Children::All || node.is_named() {
+                    children.push(SyntaxNode {
+                        node,
+                        field: cursor.field_name(),
+                        ..*self
+                    });
+                }
+
+                if !cursor.goto_next_sibling() {
+                    break;
+                }
+            }
+        }
+
+        children.into_iter()
+    }
+}
+
+impl Identified for SyntaxNode<'_> {
+    /// The id of the node within its tree
+    type Id = usize;
+
+    fn id(&self) -> Self::Id {
+        self.node.id()
+    }
+}
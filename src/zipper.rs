@@ -38,11 +38,36 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! With the `derive` feature, the same implementation can be derived instead:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use zippered::zipper::Zippable;
+//!
+//! #[derive(Clone, Zippable)]
+//! enum Tree {
+//!     Node(usize),
+//!     #[zippable(children)]
+//!     Branch(Vec<Tree>),
+//! }
+//! # }
+//! ```
+
+#[cfg(feature = "derive")]
+pub use zippered_derive::Zippable;
 
 use im::Vector;
-use std::collections::HashMap;
-use std::rc::Rc;
-use std::{cell::RefCell, fmt::Debug};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Deref;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+};
 
 /// A trait for describing how a type can be made capable of be producing a [Zipper].
 ///
@@ -56,12 +81,85 @@ where
     // fn children(&self) -> Box<dyn Iterator<Item = Self> + '_>;
     fn children(&self) -> impl Iterator<Item = Self> + '_;
 
+    /// Returns the parents of the value, for values such as graph nodes that may be reached from more than
+    /// one parent. No parents are known by default. See [Zipper::up_to]
+    fn parents(&self) -> impl Iterator<Item = Self> + '_ {
+        std::iter::empty()
+    }
+
     /// Creates and returns a [Zipper] for this value
     fn zipper(&self) -> Zipper<Self> {
-        Zipper::new(self.clone())
+        self.session().cursor()
+    }
+
+    /// Creates and returns a [ZipperSession] for handing out any number of [Zipper]s over this value
+    fn session(&self) -> ZipperSession<Self> {
+        ZipperSession::new(self.clone())
+    }
+}
+
+/// A trait for [Zippable] values whose children may fail to be listed, such as directories or remote resources.
+///
+/// [Zippable::children] is typically `self.try_children().into_iter().flatten()`, hiding any error as an absence
+/// of children. Cursors handed out by [try_zipper](#method.try_zipper), or by a session that
+/// [surfaces errors](ZipperSession::surface_errors), report the error as [ZipperErr::CannotListChildren] instead.
+pub trait TryZippable
+where
+    Self: Zippable,
+{
+    /// The type of error encountered while listing children
+    type Error: Error + Send + Sync + 'static;
+
+    /// Returns the children of the value, or the error preventing them from being listed
+    fn try_children(&self) -> Result<impl Iterator<Item = Self> + '_, Self::Error>;
+
+    /// Creates and returns a [Zipper] for this value that reports errors listing children
+    fn try_zipper(&self) -> Zipper<Self> {
+        self.session().surface_errors().cursor()
+    }
+}
+
+/// A trait for [Zippable] values that can identify themselves, such as graph nodes that may be reached
+/// by more than one path. Values with equal ids are considered to be the same node.
+pub trait Identified {
+    /// The type of the identity of a value
+    type Id: Hash + Eq;
+
+    /// Returns the identity of the value
+    fn id(&self) -> Self::Id;
+}
+
+/// A trait for [Zippable] values that are reached along labelled edges, such as weighted graph edges, object keys,
+/// or file names. Each value carries the edge leading to it from its parent, so the edge travels with the value
+/// through each move and the cache.
+pub trait Edged
+where
+    Self: Zippable,
+{
+    /// The type of the label of the edge between a value and one of its children
+    type Edge;
+
+    /// Returns the edge leading to the value from the parent that listed it, or `None` if it was not listed as a
+    /// child, such as a root.
+    fn edge(&self) -> Option<Self::Edge>;
+
+    /// Returns the children of the value along with the edge leading to each.
+    fn edged_children(&self) -> impl Iterator<Item = (Option<Self::Edge>, Self)> + '_ {
+        self.children().map(|child| (child.edge(), child))
     }
 }
 
+/// How a [Zipper] treats a node that repeats one of its own ancestors, i.e. closes a cycle.
+///
+/// See [ZipperSession::detect_cycles]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum CyclePolicy {
+    /// Moving onto a repeated node fails with [ZipperErr::Cycle]
+    Error,
+    /// Moving onto a repeated node succeeds, but it is treated as having no children
+    Leaf,
+}
+
 /// A unit of movement in a direction that a [Zipper] uses to traverse a [Zippable] tree.
 ///
 /// See [Zipper::travel]
@@ -72,27 +170,39 @@ pub enum Step {
     Left,
     Right,
     Back,
+    /// Up to the [parent](Zippable::parents) at the given index, which becomes the new root. See [Zipper::up_to]
+    UpTo(usize),
 }
 
-type Path = Vector<Step>;
+pub(crate) type Path = Vector<Step>;
+
+/// A [Path] along with the id of the root it leads from.
+pub(crate) type Position = (usize, Path);
 
-/// A record of the [Step]s taken by a [Zipper].
+/// A record of the [Step]s taken by a [Zipper]. The path leads from the root identified by `root_id`, which
+/// changes whenever the [Zipper] pivots [up to](Step::UpTo) one of its value's parents.
 #[derive(Debug, Clone)]
-struct History {
-    path: Path,
-    journey: Path,
+pub(crate) struct History {
+    pub(crate) root_id: usize,
+    pub(crate) path: Path,
+    pub(crate) journey: Path,
 }
 
 impl History {
-    fn new() -> Self {
+    pub(crate) fn new(root_id: usize) -> Self {
         Self {
+            root_id,
             path: Path::new(),
             journey: Path::new(),
         }
     }
 
-    fn step(self, direction: Step) -> History {
-        let mut next = self.clone();
+    fn position(&self) -> Position {
+        (self.root_id, self.path.clone())
+    }
+
+    pub(crate) fn step(self, direction: Step) -> History {
+        let mut next = self;
 
         match direction {
             // A step in any of these directions effectively erases the end of the path as it is a backwards step
@@ -107,6 +217,10 @@ impl History {
                 // finally, pop the Down step
                 next.path.pop_back();
             }
+            // the new root is the start of a new path
+            Step::UpTo(_) => {
+                next.path.clear();
+            }
             _ => {
                 next.path.push_back(direction);
             }
@@ -118,46 +232,571 @@ impl History {
     }
 }
 
+/// The [Step]s leading from one [Path] to another by way of their closest common ancestor.
+fn route(from: &Path, to: &Path) -> Vec<Step> {
+    let common = from
+        .iter()
+        .zip(to.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // an ancestor's path is always followed by a Down step, never by a Right step
+    let ancestor = (0..=common)
+        .rev()
+        .find(|&len| {
+            [from, to]
+                .iter()
+                .all(|path| path.get(len).is_none_or(|step| *step == Step::Down))
+        })
+        .unwrap_or(0);
+
+    let ups = from
+        .iter()
+        .skip(ancestor)
+        .filter(|step| **step == Step::Down)
+        .map(|_| Step::Up);
+
+    ups.chain(to.iter().skip(ancestor).cloned()).collect()
+}
+
+/// The [Path] of the parent of the value at `path`.
+pub(crate) fn parent_path(path: &Path) -> Path {
+    let mut parent = path.clone();
+    while parent.last() == Some(&Step::Right) {
+        parent.pop_back();
+    }
+    parent.pop_back();
+    parent
+}
+
+/// Every position visited by a zipper, such as a [Zipper], keyed by [Position].
 #[derive(Clone)]
-struct SingletonNodeCache<T>
+pub(crate) struct SingletonNodeCache<Z>
+where
+    Z: Clone,
+{
+    storage: Rc<RefCell<HashMap<Position, Z>>>,
+    hits: Rc<Cell<usize>>,
+    misses: Rc<Cell<usize>>,
+}
+
+impl<Z> SingletonNodeCache<Z>
+where
+    Z: Clone,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            storage: Rc::new(RefCell::new(HashMap::new())),
+            hits: Rc::new(Cell::new(0)),
+            misses: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// The zipper at `history`'s position, if visited before, counting the lookup as a hit or a miss.
+    pub(crate) fn find(&self, history: &History) -> Option<Z> {
+        let found = (*self.storage).borrow().get(&history.position()).cloned();
+
+        let counter = match found {
+            Some(_) => &self.hits,
+            None => &self.misses,
+        };
+        counter.set(counter.get() + 1);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(hit = found.is_some(), path = ?history.path, "cache lookup");
+
+        found
+    }
+
+    pub(crate) fn get(&self, position: &Position) -> Option<Z> {
+        (*self.storage).borrow().get(position).cloned()
+    }
+
+    pub(crate) fn contains(&self, history: &History) -> bool {
+        (*self.storage).borrow().contains_key(&history.position())
+    }
+
+    pub(crate) fn insert(&self, history: &History, zipper: Z) {
+        self.storage.borrow_mut().insert(history.position(), zipper);
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        (*self.storage).borrow().len()
+    }
+
+    pub(crate) fn hits(&self) -> usize {
+        self.hits.get()
+    }
+
+    pub(crate) fn misses(&self) -> usize {
+        self.misses.get()
+    }
+}
+
+impl<Z> std::fmt::Debug for SingletonNodeCache<Z>
+where
+    Z: Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SingletonNodeCache")
+            .field("entries", &self.len())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+/// Named positions shared by all [Zipper]s of a [ZipperSession].
+#[derive(Debug, Clone)]
+struct Bookmarks {
+    storage: Rc<RefCell<BTreeMap<String, Position>>>,
+}
+
+impl Bookmarks {
+    fn new() -> Self {
+        Self {
+            storage: Rc::new(RefCell::new(BTreeMap::new())),
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<Position> {
+        (*self.storage).borrow().get(name).cloned()
+    }
+
+    fn insert(&self, name: String, history: &History) {
+        self.storage.borrow_mut().insert(name, history.position());
+    }
+
+    fn remove(&self, name: &str) -> bool {
+        self.storage.borrow_mut().remove(name).is_some()
+    }
+
+    fn list(&self) -> Vec<(String, Vec<Step>)> {
+        (*self.storage)
+            .borrow()
+            .iter()
+            .map(|(name, (_, path))| (name.clone(), path.iter().cloned().collect()))
+            .collect()
+    }
+}
+
+type CursorPosition = (Weak<Cursor>, Path);
+
+/// The latest position of every active cursor of a [ZipperSession].
+#[derive(Debug, Clone)]
+struct Cursors {
+    next: Rc<Cell<usize>>,
+    storage: Rc<RefCell<BTreeMap<usize, CursorPosition>>>,
+}
+
+/// Identifies a cursor handed out by a [ZipperSession]. A cursor stays active for as long as any
+/// [Zipper] moved or cloned from it is alive.
+#[derive(Debug)]
+struct Cursor(usize);
+
+impl Cursors {
+    fn new() -> Self {
+        Self {
+            next: Rc::new(Cell::new(0)),
+            storage: Rc::new(RefCell::new(BTreeMap::new())),
+        }
+    }
+
+    fn open(&self) -> Rc<Cursor> {
+        let cursor = Rc::new(Cursor(self.next.get()));
+        self.next.set(cursor.0 + 1);
+        self.update(&cursor, &Path::new());
+        cursor
+    }
+
+    fn update(&self, cursor: &Rc<Cursor>, path: &Path) {
+        self.storage
+            .borrow_mut()
+            .insert(cursor.0, (Rc::downgrade(cursor), path.clone()));
+    }
+
+    fn list(&self) -> Vec<(usize, Vec<Step>)> {
+        let mut storage = self.storage.borrow_mut();
+        storage.retain(|_, (cursor, _)| cursor.strong_count() > 0);
+        storage
+            .iter()
+            .map(|(id, (_, path))| (*id, path.iter().cloned().collect()))
+            .collect()
+    }
+}
+
+/// Detects values that repeat one of their ancestors.
+#[derive(Clone)]
+pub(crate) struct Cycles<T>
 where
     T: Zippable,
 {
-    storage: Rc<RefCell<HashMap<Path, Zipper<T>>>>,
+    pub(crate) policy: CyclePolicy,
+    /// Hashes the id of a value, once per position, so that only ancestors with the same hash are compared
+    pub(crate) hash: fn(&T) -> u64,
+    /// Whether two values have the same id
+    pub(crate) same: fn(&T, &T) -> bool,
 }
 
-impl<T> SingletonNodeCache<T>
+impl<T> std::fmt::Debug for Cycles<T>
 where
     T: Zippable,
 {
-    fn new() -> Self {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cycles")
+            .field("policy", &self.policy)
+            .finish()
+    }
+}
+
+/// Nodes whose ids share a hash, alongside their children.
+type Bucket<T> = Vec<(T, Rc<[T]>)>;
+
+/// Children keyed by the [id](Identified::id) of their parent rather than by [Path], so that a node reachable
+/// by many paths only has its children computed once.
+#[derive(Clone)]
+struct ChildrenCache<T>
+where
+    T: Zippable,
+{
+    hash: fn(&T) -> u64,
+    same: fn(&T, &T) -> bool,
+    storage: Rc<RefCell<HashMap<u64, Bucket<T>>>>,
+}
+
+impl<T> ChildrenCache<T>
+where
+    T: Zippable,
+{
+    fn new() -> Self
+    where
+        T: Identified,
+    {
         Self {
+            hash: |node| {
+                let mut hasher = DefaultHasher::new();
+                node.id().hash(&mut hasher);
+                hasher.finish()
+            },
+            same: |a, b| a.id() == b.id(),
             storage: Rc::new(RefCell::new(HashMap::new())),
         }
     }
 
-    fn find(&self, path: &Path) -> Option<Zipper<T>> {
-        (*self.storage).borrow().get(path).cloned()
-    }
+    /// The children of `node`, listed by `list` unless a node with the same id was listed before.
+    fn children(
+        &self,
+        node: &T,
+        list: impl FnOnce(&T) -> Result<Rc<[T]>, ListError>,
+    ) -> Result<Rc<[T]>, ListError> {
+        let hash = (self.hash)(node);
+
+        let cached = (*self.storage).borrow().get(&hash).and_then(|bucket| {
+            bucket
+                .iter()
+                .find(|(other, _)| (self.same)(other, node))
+                .map(|(_, children)| children.clone())
+        });
+
+        if let Some(children) = cached {
+            return Ok(children);
+        }
 
-    fn insert(&self, path: &Path, zipper: Zipper<T>) {
+        // errors are not cached, so that listing is retried
+        let children = list(node)?;
         self.storage
             .borrow_mut()
-            .insert(path.clone(), zipper.clone());
+            .entry(hash)
+            .or_default()
+            .push((node.clone(), children.clone()));
+        Ok(children)
     }
 }
 
-impl<T> std::fmt::Debug for SingletonNodeCache<T>
+impl<T> std::fmt::Debug for ChildrenCache<T>
 where
     T: Zippable,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("SingletonNodeCache")
-            .field("entries", &(*self.storage).borrow().len())
+        f.debug_struct("ChildrenCache")
+            .field(
+                "entries",
+                &(*self.storage)
+                    .borrow()
+                    .values()
+                    .map(Vec::len)
+                    .sum::<usize>(),
+            )
             .finish()
     }
 }
 
+/// The error preventing children from being listed, shared by every [ZipperErr] that reports it.
+type ListError = Arc<dyn Error + Send + Sync>;
+
+/// Lists children via [TryZippable::try_children], with the error type erased.
+type TryChildren<T> = for<'a> fn(&'a T) -> Result<Box<dyn Iterator<Item = T> + 'a>, ListError>;
+
+/// Everything that the [Zipper]s of a [ZipperSession] share with one another.
+#[derive(Debug, Clone)]
+pub(crate) struct Shared<T>
+where
+    T: Zippable,
+{
+    pub(crate) cache: SingletonNodeCache<Frame<T>>,
+    bookmarks: Bookmarks,
+    cursors: Cursors,
+    pub(crate) cycles: Option<Cycles<T>>,
+    children: Option<ChildrenCache<T>>,
+    fallible: Option<TryChildren<T>>,
+    roots: Rc<Cell<usize>>,
+    listed: Rc<Cell<usize>>,
+}
+
+impl<T> Shared<T>
+where
+    T: Zippable,
+{
+    /// Lists the children of `node` afresh, counting the call, and hands them to `consume`, or returns the error
+    /// listing them if the session surfaces errors.
+    fn list<R>(
+        &self,
+        node: &T,
+        consume: impl FnOnce(&mut dyn Iterator<Item = T>) -> R,
+    ) -> Result<R, ListError> {
+        self.listed.set(self.listed.get() + 1);
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!("listing children");
+
+        match self.fallible {
+            Some(list) => Ok(consume(&mut list(node)?)),
+            // without boxing, as this is the common case
+            None => Ok(consume(&mut node.children())),
+        }
+    }
+
+    /// The children of `node` from the children cache, listing them if they are not cached yet.
+    fn cached(&self, cache: &ChildrenCache<T>, node: &T) -> Result<Rc<[T]>, ListError> {
+        cache.children(node, |node| self.list(node, |children| children.collect()))
+    }
+
+    /// The `index`th child of `node`, if it exists, or the error listing children if the session surfaces errors.
+    fn child(&self, node: &T, index: usize) -> Result<Option<T>, ListError> {
+        match &self.children {
+            Some(cache) => Ok(self.cached(cache, node)?.get(index).cloned()),
+            None => self.list(node, |children| children.nth(index)),
+        }
+    }
+
+    /// Allocates the id of a new root, see [History].
+    fn new_root(&self) -> usize {
+        let root_id = self.roots.get();
+        self.roots.set(root_id + 1);
+        root_id
+    }
+
+    /// The hash of the id of `node` if the session detects cycles, see [Cycles::hash].
+    pub(crate) fn hash(&self, node: &T) -> Option<u64> {
+        self.cycles.as_ref().map(|cycles| (cycles.hash)(node))
+    }
+
+    /// All children of `node`, or the error listing them if the session surfaces errors.
+    pub(crate) fn children(&self, node: &T) -> Result<Vec<T>, ListError> {
+        match &self.children {
+            Some(cache) => Ok(self.cached(cache, node)?.to_vec()),
+            None => self.list(node, |children| children.collect()),
+        }
+    }
+
+    /// The number of children of `node`, or the error listing them if the session surfaces errors.
+    fn child_count(&self, node: &T) -> Result<usize, ListError> {
+        match &self.children {
+            Some(cache) => Ok(self.cached(cache, node)?.len()),
+            None => self.list(node, |children| children.count()),
+        }
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.cache.hits(),
+            misses: self.cache.misses(),
+            entries: self.cache.len(),
+            children_calls: self.listed.get(),
+        }
+    }
+}
+
+/// Hands out any number of [Zipper]s, or cursors, over the same root. Cursors move independently
+/// of one another but share a single cache of visited nodes and a single set of bookmarks, so a
+/// node computed by one cursor is never recomputed by another.
+///
+/// Cloning a [Zipper] does not create a new cursor; use [cursor](#method.cursor) for that.
+#[derive(Clone)]
+pub struct ZipperSession<T>
+where
+    T: Zippable,
+{
+    root: T,
+    root_id: usize,
+    shared: Shared<T>,
+}
+
+impl<T> ZipperSession<T>
+where
+    T: Zippable,
+{
+    /// Creates a new session over `root` without any cursors.
+    pub fn new(root: T) -> Self {
+        Self {
+            root,
+            root_id: 0,
+            shared: Shared {
+                cache: SingletonNodeCache::new(),
+                bookmarks: Bookmarks::new(),
+                cursors: Cursors::new(),
+                cycles: None,
+                children: None,
+                fallible: None,
+                roots: Rc::new(Cell::new(1)),
+                listed: Rc::new(Cell::new(0)),
+            },
+        }
+    }
+
+    /// Returns the session with cycle detection enabled for the cursors it hands out. A node closes a cycle
+    /// when its [id](Identified::id) equals that of one of its ancestors, and is then handled according to `policy`.
+    ///
+    /// Without cycle detection, exploring a cyclic graph may never reach a leaf.
+    pub fn detect_cycles(mut self, policy: CyclePolicy) -> Self
+    where
+        T: Identified,
+    {
+        self.shared.cycles = Some(Cycles {
+            policy,
+            hash: |node| {
+                let mut hasher = DefaultHasher::new();
+                node.id().hash(&mut hasher);
+                hasher.finish()
+            },
+            same: |a, b| a.id() == b.id(),
+        });
+        self
+    }
+
+    /// Returns the session with children cached by the [id](Identified::id) of their parent for the cursors it hands
+    /// out, so that each node of a graph with shared subtrees, such as a DAG, has [Zippable::children] called at most
+    /// once regardless of how many paths lead to it.
+    ///
+    /// Positions are still cached by path, as moving [left](Zipper::left) or [back](Zipper::back) relies on them.
+    pub fn cache_children_by_id(mut self) -> Self
+    where
+        T: Identified,
+    {
+        self.shared.children = Some(ChildrenCache::new());
+        self
+    }
+
+    /// Returns the session with errors listing children reported by the cursors it hands out, as
+    /// [ZipperErr::CannotListChildren], rather than hidden as an absence of children.
+    ///
+    /// Queries such as [Zipper::is_leaf] and [Zipper::descendants] still treat a value whose children cannot be
+    /// listed as having none.
+    pub fn surface_errors(mut self) -> Self
+    where
+        T: TryZippable,
+    {
+        self.shared.fallible = Some(|node| match node.try_children() {
+            Ok(children) => Ok(Box::new(children)),
+            Err(err) => Err(Arc::new(err)),
+        });
+        self
+    }
+
+    /// Returns a new cursor positioned at the root.
+    pub fn cursor(&self) -> Zipper<T> {
+        Zipper {
+            node: self.root.clone(),
+            hash: self.shared.hash(&self.root),
+            parent: None,
+            index_in_parent: None,
+            history: History::new(self.root_id),
+            shared: self.shared.clone(),
+            cursor: Some(self.shared.cursors.open()),
+        }
+    }
+
+    /// The ids and paths of all active cursors, ordered by id. See [Zipper::cursor].
+    pub fn cursors(&self) -> Vec<(usize, Vec<Step>)> {
+        self.shared.cursors.list()
+    }
+
+    /// How often the cursors of this session have found their positions in its cache, and how often they
+    /// have listed children.
+    pub fn stats(&self) -> CacheStats {
+        self.shared.stats()
+    }
+
+    /// The names and paths of all bookmarks, ordered by name. See [Zipper::mark].
+    pub fn bookmarks(&self) -> Vec<(String, Vec<Step>)> {
+        self.shared.bookmarks.list()
+    }
+}
+
+/// A level above a [Zipper]'s position: a value whose children are being explored, along with its own position
+/// amongst its siblings. Frames are shared by every [Zipper] below them; only the [Zipper] itself keeps a [History].
+///
+/// The cache of visited positions holds frames too, as a [Zipper] found there takes on the [History] of the move
+/// that found it.
+pub(crate) struct Frame<T> {
+    pub(crate) node: T,
+    pub(crate) hash: Option<u64>,
+    index: Option<usize>,
+    parent: Option<Rc<Frame<T>>>,
+}
+
+impl<T> Clone for Frame<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Frame {
+            node: self.node.clone(),
+            hash: self.hash,
+            index: self.index,
+            parent: self.parent.clone(),
+        }
+    }
+}
+
+impl<T> Frame<T>
+where
+    T: Zippable,
+{
+    /// The [Frame] of `zipper`'s position.
+    fn of(zipper: &Zipper<T>) -> Self {
+        Frame {
+            node: zipper.node.clone(),
+            hash: zipper.hash,
+            index: zipper.index_in_parent,
+            parent: zipper.parent.clone(),
+        }
+    }
+
+    /// A [Zipper] positioned at this frame's value, reached by `history`.
+    fn zipper(&self, history: History, shared: Shared<T>) -> Zipper<T> {
+        Zipper {
+            node: self.node.clone(),
+            hash: self.hash,
+            parent: self.parent.clone(),
+            index_in_parent: self.index,
+            history,
+            shared,
+            cursor: None,
+        }
+    }
+}
+
 /// A cursor over a tree structure of [Zippable]s. Can be moved up, down, left, and right through
 /// the tree and records traversal history as moves. A Zipper considers a tree's root to be at the top,
 /// getting wider at the bottom. As such, Zipper cannot move `up`, `left`, or `right` from its starting position, nor
@@ -170,60 +809,105 @@ where
     T: Zippable,
 {
     pub node: T,
-    history: History,
-    parent: Option<Rc<Zipper<T>>>,
+    /// The hash of the id of the current value, if the session detects cycles
+    pub(crate) hash: Option<u64>,
+    pub(crate) history: History,
+    pub(crate) parent: Option<Rc<Frame<T>>>,
     index_in_parent: Option<usize>,
-    cache: SingletonNodeCache<T>,
+    pub(crate) shared: Shared<T>,
+    cursor: Option<Rc<Cursor>>,
 }
 
 impl<T> Zipper<T>
 where
     T: Zippable,
 {
-    fn new(root: T) -> Self {
-        Zipper {
-            node: root,
-            parent: None,
-            index_in_parent: None,
-            history: History::new(),
-            cache: SingletonNodeCache::new(),
+    /// Hands `cursor` over to this position, recording it as the cursor's latest position, or returns
+    /// [ZipperErr::Cycle] if the position closes a cycle its session does not allow.
+    fn arrive(mut self, cursor: Option<Rc<Cursor>>) -> Result<Self, ZipperErr> {
+        if self.cycle() == Some(CyclePolicy::Error) {
+            return Err(ZipperErr::Cycle);
         }
+
+        if let Some(cursor) = &cursor {
+            self.shared.cursors.update(cursor, &self.history.path);
+        }
+        self.cursor = cursor;
+        Ok(self)
+    }
+
+    /// The error for failing to list the children of the value at `path`.
+    fn cannot_list(path: &Path, source: ListError) -> ZipperErr {
+        ZipperErr::CannotListChildren {
+            path: path.iter().cloned().collect(),
+            source,
+        }
+    }
+
+    /// The [Frame]s of the current value's parent, grandparent, and so on up to the root.
+    pub(crate) fn ancestors(&self) -> impl Iterator<Item = &Frame<T>> {
+        std::iter::successors(self.parent.as_deref(), |p| p.parent.as_deref())
+    }
+
+    /// The session's [CyclePolicy] if the current value closes a cycle, i.e. repeats one of its ancestors.
+    ///
+    /// Only ancestors whose id hashes the same as the current value's have their ids compared.
+    pub(crate) fn cycle(&self) -> Option<CyclePolicy> {
+        let cycles = self.shared.cycles.as_ref()?;
+
+        self.ancestors()
+            .any(|frame| frame.hash == self.hash && (cycles.same)(&self.node, &frame.node))
+            .then_some(cycles.policy)
     }
 
     /// Returns a new Zipper after moving down to this value's first child, or [ZipperErr.CannotMoveDown] if
     /// no children can or do exist.
     pub fn down(self) -> Result<Zipper<T>, ZipperErr> {
+        self.traced(Step::Down, Self::go_down)
+    }
+
+    /// Moves down without tracing the move. See [down](#method.down).
+    fn go_down(self) -> Result<Zipper<T>, ZipperErr> {
+        // never continue a cycle
+        if self.cycle().is_some() {
+            return Err(ZipperErr::CannotGoDown);
+        }
+
         // this is where we want to go
-        let next_history = self.history.clone().step(Step::Down);
+        let next_history = self.history.step(Step::Down);
         // check cache and return if possible
-        if let Some(mut cached) = self.cache.find(&next_history.path) {
-            cached.history = next_history;
-            return Ok(cached);
+        if let Some(cached) = self.shared.cache.find(&next_history) {
+            return cached.zipper(next_history, self.shared).arrive(self.cursor);
         }
 
         // see if we can move
-        match self.node.children().next() {
+        let first = self
+            .shared
+            .child(&self.node, 0)
+            .map_err(|source| Self::cannot_list(&parent_path(&next_history.path), source))?;
+        match first {
             // we can
             Some(first) => {
-                // see if we've been to this path before
                 let next = Zipper {
-                    node: first.clone(),
-                    parent: Some(Rc::new(Zipper {
-                        node: self.node.clone(),
-                        parent: self.parent.clone(),
-                        index_in_parent: self.index_in_parent,
-                        history: self.history,
-                        cache: self.cache.clone(),
+                    hash: self.shared.hash(&first),
+                    node: first,
+                    // the current value becomes a frame, leaving its history behind
+                    parent: Some(Rc::new(Frame {
+                        node: self.node,
+                        hash: self.hash,
+                        index: self.index_in_parent,
+                        parent: self.parent,
                     })),
                     index_in_parent: Some(0),
                     history: next_history,
-                    cache: self.cache.clone(),
+                    shared: self.shared,
+                    cursor: None,
                 };
 
                 // add to cache
-                self.cache.insert(&next.history.path, next.clone());
+                next.shared.cache.insert(&next.history, Frame::of(&next));
 
-                Ok(next)
+                next.arrive(self.cursor)
             }
             None => Err(ZipperErr::CannotGoDown),
         }
@@ -232,14 +916,15 @@ where
     /// Returns a new Zipper after moving up to this value's parent, or [ZipperErr.CannotMoveUp] if
     /// already at the root / top of the tree.
     pub fn up(self) -> Result<Zipper<T>, ZipperErr> {
+        self.traced(Step::Up, Self::go_up)
+    }
+
+    /// Moves up without tracing the move. See [up](#method.up).
+    fn go_up(self) -> Result<Zipper<T>, ZipperErr> {
         match self.parent {
-            Some(ref parent) => Ok(Zipper {
-                node: parent.node.clone(),
-                parent: parent.parent.clone(),
-                index_in_parent: parent.index_in_parent,
-                history: self.history.step(Step::Up),
-                cache: self.cache,
-            }),
+            Some(ref parent) => parent
+                .zipper(self.history.step(Step::Up), self.shared)
+                .arrive(self.cursor),
             None => Err(ZipperErr::CannotGoUp),
         }
     }
@@ -247,36 +932,45 @@ where
     /// Returns a new Zipper after moving right to this value's next sibling, or [ZipperErr.CannotMoveRight] if
     /// no right sibling exists.
     pub fn right(self) -> Result<Zipper<T>, ZipperErr> {
+        self.traced(Step::Right, Self::go_right)
+    }
+
+    /// Moves right without tracing the move. See [right](#method.right).
+    fn go_right(self) -> Result<Zipper<T>, ZipperErr> {
         // this is where we want to go
-        let next_history = self.history.clone().step(Step::Right);
+        let next_history = self.history.step(Step::Right);
         // check cache and return if possible
-        if let Some(mut cached) = self.cache.find(&next_history.path) {
-            cached.history = next_history;
-            return Ok(cached);
+        if let Some(cached) = self.shared.cache.find(&next_history) {
+            return cached.zipper(next_history, self.shared).arrive(self.cursor);
         }
 
         // see if we can move
-        match (
-            self.index_in_parent,
-            self.parent.as_ref().map(|p| p.node.children()),
-        ) {
+        match (self.index_in_parent, self.parent) {
             // we can
-            (Some(index), Some(mut children)) => {
+            (Some(index), Some(parent)) => {
                 let right_index = index + 1;
-                match children.nth(right_index) {
+                let right = self
+                    .shared
+                    .child(&parent.node, right_index)
+                    .map_err(|source| {
+                        Self::cannot_list(&parent_path(&next_history.path), source)
+                    })?;
+                match right {
                     Some(right) => {
                         let next = Zipper {
+                            hash: self.shared.hash(&right),
                             node: right,
-                            parent: self.parent.clone(),
-                            index_in_parent: right_index.into(),
+                            parent: Some(parent),
+                            index_in_parent: Some(right_index),
                             history: next_history,
-                            cache: self.cache.clone(),
+                            shared: self.shared,
+                            cursor: None,
                         };
 
                         // add to cache
-                        self.cache.insert(&next.history.path, next.clone());
+                        next.shared.cache.insert(&next.history, Frame::of(&next));
 
-                        Ok(next)
+                        next.arrive(self.cursor)
                     }
                     _ => Err(ZipperErr::CannotGoRight),
                 }
@@ -288,38 +982,54 @@ where
     /// Returns a new Zipper after moving left to this value's previous sibling, or [ZipperErr.CannotMoveLeft] if
     /// no left sibling exists.
     pub fn left(self) -> Result<Zipper<T>, ZipperErr> {
+        self.traced(Step::Left, Self::go_left)
+    }
+
+    /// Moves left without tracing the move. See [left](#method.left).
+    fn go_left(self) -> Result<Zipper<T>, ZipperErr> {
+        // the first child has no left sibling, even though its path without the last step leads to its parent
+        if !self.has_left() {
+            return Err(ZipperErr::CannotGoLeft);
+        }
+
         // this is where we want to go
-        let next_history = self.history.clone().step(Step::Left);
+        let next_history = self.history.step(Step::Left);
         // check cache and return if possible
-        if let Some(mut cached) = self.cache.find(&next_history.path) {
-            cached.history = next_history;
-            return Ok(cached);
+        if let Some(cached) = self.shared.cache.find(&next_history) {
+            return cached.zipper(next_history, self.shared).arrive(self.cursor);
         }
 
-        dbg!("We should really never be here if caching is working.");
+        // only reached from positions that were never cached, such as those of descendants
+        #[cfg(feature = "tracing")]
+        tracing::debug!(path = ?next_history.path, "left sibling not cached");
 
         // see if we can move
-        match (
-            self.index_in_parent,
-            self.parent.as_ref().map(|p| p.node.children()),
-        ) {
+        match (self.index_in_parent, self.parent) {
             // we can
-            (Some(index), Some(mut children)) if index > 0 => {
+            (Some(index), Some(parent)) if index > 0 => {
                 let left_index = index - 1;
-                match children.nth(left_index) {
+                let left = self
+                    .shared
+                    .child(&parent.node, left_index)
+                    .map_err(|source| {
+                        Self::cannot_list(&parent_path(&next_history.path), source)
+                    })?;
+                match left {
                     Some(left) => {
                         let next = Zipper {
+                            hash: self.shared.hash(&left),
                             node: left,
-                            parent: self.parent.clone(),
+                            parent: Some(parent),
                             index_in_parent: Some(left_index),
                             history: next_history,
-                            cache: self.cache.clone(),
+                            shared: self.shared,
+                            cursor: None,
                         };
 
                         // add to cache
-                        self.cache.insert(&next.history.path, next.clone());
+                        next.shared.cache.insert(&next.history, Frame::of(&next));
 
-                        Ok(next)
+                        next.arrive(self.cursor)
                     }
                     None => Err(ZipperErr::CannotGoLeft),
                 }
@@ -331,26 +1041,94 @@ where
     /// Returns a new Zipper after moving to the step prior the current value, or [ZipperErr.CannotMoveBack] if
     /// there have not yet been any [Step]s taken.
     pub fn back(self) -> Result<Zipper<T>, ZipperErr> {
+        self.traced(Step::Back, Self::go_back)
+    }
+
+    /// Moves back without tracing the move. See [back](#method.back).
+    fn go_back(self) -> Result<Zipper<T>, ZipperErr> {
         // this is where we want to go
-        let next_history = self.history.clone().step(Step::Back);
+        let next_history = self.history.step(Step::Back);
 
         // check cache and return if possible
-        if let Some(mut cached) = self.cache.find(&next_history.path) {
-            cached.history = next_history;
-            return Ok(cached);
+        if let Some(cached) = self.shared.cache.find(&next_history) {
+            return cached.zipper(next_history, self.shared).arrive(self.cursor);
         }
 
         // there is no traversal path, we are at the top, use parent if it exists
         match self.parent {
-            Some(parent) if next_history.path.len() == 0 => {
-                let mut next = parent.as_ref().clone();
-                next.history = next_history;
-                Ok(next)
+            Some(parent) if next_history.path.is_empty() => {
+                parent.zipper(next_history, self.shared).arrive(self.cursor)
             }
             _ => Err(ZipperErr::CannotGoBack),
         }
     }
 
+    /// The [parents](Zippable::parents) of the current value, only one of which is the parent this Zipper came from.
+    pub fn parents(&self) -> impl Iterator<Item = T> + '_ {
+        self.node.parents()
+    }
+
+    /// Returns a new Zipper after moving up to the first of this value's [parents](Zippable::parents) accepted by
+    /// `selector`, or [ZipperErr.CannotMoveUp] if none is.
+    ///
+    /// Unlike [up](#method.up), this can pivot to a parent other than the one this Zipper came from, such as another
+    /// dependent in a dependency graph. The parent becomes the root of a new path: it has no parent of its own, and
+    /// the [path](#method.path) starts afresh from it. The pivot is recorded in the [journey](#method.journey) as
+    /// [Step::UpTo] so that the journey can still be replayed.
+    pub fn up_to(self, mut selector: impl FnMut(&T) -> bool) -> Result<Zipper<T>, ZipperErr> {
+        let parent = self
+            .node
+            .parents()
+            .enumerate()
+            .find(|(_, parent)| selector(parent));
+
+        match parent {
+            Some((index, parent)) => self.pivot(index, parent),
+            None => Err(ZipperErr::CannotGoUp),
+        }
+    }
+
+    /// Returns a new Zipper rooted at `parent`, the `index`th of this value's parents.
+    fn pivot(self, index: usize, parent: T) -> Result<Zipper<T>, ZipperErr> {
+        self.traced(Step::UpTo(index), |zipper| {
+            let mut history = zipper.history.step(Step::UpTo(index));
+            history.root_id = zipper.shared.new_root();
+
+            Zipper {
+                hash: zipper.shared.hash(&parent),
+                node: parent,
+                parent: None,
+                index_in_parent: None,
+                history,
+                shared: zipper.shared,
+                cursor: None,
+            }
+            .arrive(zipper.cursor)
+        })
+    }
+
+    /// Takes `step` by way of `movement`, tracing the step, the resulting path and any error when the `tracing`
+    /// feature is enabled.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn traced(
+        self,
+        step: Step,
+        movement: impl FnOnce(Self) -> Result<Zipper<T>, ZipperErr>,
+    ) -> Result<Zipper<T>, ZipperErr> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("step", ?step, from = ?self.history.path).entered();
+
+        let moved = movement(self);
+
+        #[cfg(feature = "tracing")]
+        match &moved {
+            Ok(next) => tracing::trace!(to = ?next.history.path, "moved"),
+            Err(err) => tracing::trace!(%err, "cannot move"),
+        }
+
+        moved
+    }
+
     /// Return a new Zipper after taking a [Step] in the specified direction, or the relevant [ZipperErr] if
     /// no step can be taken.
     pub fn step(self, step: &Step) -> Result<Zipper<T>, ZipperErr> {
@@ -360,6 +1138,13 @@ where
             Step::Left => self.left(),
             Step::Right => self.right(),
             Step::Back => self.back(),
+            Step::UpTo(index) => {
+                let parent = self.node.parents().nth(*index);
+                match parent {
+                    Some(parent) => self.pivot(*index, parent),
+                    None => Err(ZipperErr::CannotGoUp),
+                }
+            }
         }
     }
 
@@ -375,6 +1160,80 @@ where
         Ok(zipper)
     }
 
+    /// How often the [Zipper]s of this session have found their positions in its cache, and how often they
+    /// have listed children. See [ZipperSession::stats].
+    pub fn stats(&self) -> CacheStats {
+        self.shared.stats()
+    }
+
+    /// The id of the [ZipperSession] cursor this [Zipper] is a position of. See [ZipperSession::cursors].
+    pub fn cursor(&self) -> Option<usize> {
+        self.cursor.as_ref().map(|cursor| cursor.0)
+    }
+
+    /// Returns the [ZipperSession] this [Zipper] belongs to, for handing out further cursors that share
+    /// its cache and bookmarks. Those cursors start at the root of this Zipper's [path](#method.path).
+    pub fn session(&self) -> ZipperSession<T> {
+        let root = self
+            .ancestors()
+            .last()
+            .map_or(&self.node, |root| &root.node);
+
+        ZipperSession {
+            root: root.clone(),
+            root_id: self.history.root_id,
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Records the [Zipper]'s current position under `name`, replacing any previous position of that name.
+    ///
+    /// Bookmarks are shared with every [Zipper] of the same [ZipperSession].
+    pub fn mark(&self, name: impl Into<String>) {
+        self.shared.bookmarks.insert(name.into(), &self.history);
+    }
+
+    /// Forgets the bookmark recorded under `name`, returning whether it existed.
+    pub fn unmark(&self, name: &str) -> bool {
+        self.shared.bookmarks.remove(name)
+    }
+
+    /// The names and paths of all bookmarks, ordered by name.
+    pub fn bookmarks(&self) -> Vec<(String, Vec<Step>)> {
+        self.shared.bookmarks.list()
+    }
+
+    /// Returns a new Zipper positioned at the bookmark recorded under `name`, or [ZipperErr.UnknownBookmark]
+    /// if no such bookmark exists. Fails with [ZipperErr.CannotJump] if the bookmark was recorded under a
+    /// different root, i.e. before or after pivoting [up to](#method.up_to) another parent.
+    ///
+    /// The jump is recorded in the [journey](#method.journey) as the [Step]s leading from the current position
+    /// to the bookmark via their closest common ancestor.
+    pub fn jump(self, name: &str) -> Result<Zipper<T>, ZipperErr> {
+        let (root, target) = self
+            .shared
+            .bookmarks
+            .find(name)
+            .ok_or(ZipperErr::UnknownBookmark)?;
+        if root != self.history.root_id {
+            return Err(ZipperErr::CannotJump);
+        }
+
+        let route = route(&self.history.path, &target);
+        let next_history = route
+            .iter()
+            .cloned()
+            .fold(self.history.clone(), History::step);
+
+        // check cache and return if possible
+        if let Some(cached) = self.shared.cache.find(&next_history) {
+            return cached.zipper(next_history, self.shared).arrive(self.cursor);
+        }
+
+        // the root is never cached, so walk there instead
+        self.travel(route.into_iter())
+    }
+
     /// The the most direct sequence of [Step]s taken to reach the [Zipper]'s current position.
     pub fn path(&self) -> impl Iterator<Item = Step> + '_ {
         self.history.path.iter().cloned()
@@ -387,22 +1246,329 @@ where
         self.history.journey.iter().cloned()
     }
 
-    /// Prints the current state of the [Zipper] via dbg
+    /// Returns an [Iterator] over [Zipper]s positioned at every descendant of the current value, in depth-first
+    /// pre-order. The [path](#method.path) and [journey](#method.journey) of each lead directly from the current position.
+    ///
+    /// Honours the session's [CyclePolicy]: under [CyclePolicy::Error] nodes that close a cycle are skipped, and
+    /// under [CyclePolicy::Leaf] they are visited but not descended into.
+    pub fn descendants(&self) -> Descendants<T> {
+        Descendants {
+            expand: self.cycle().is_none().then(|| Zipper {
+                cursor: None,
+                ..self.clone()
+            }),
+            stack: Vec::new(),
+            shared: self.shared.clone(),
+            cursor: self.cursor.clone(),
+            max_depth: None,
+        }
+    }
+
+    /// The edge leading from the parent of the current value to the current value, or `None` at the root.
+    ///
+    /// The edge is carried by the value itself, see [Edged::edge].
+    pub fn edge(&self) -> Option<T::Edge>
+    where
+        T: Edged,
+    {
+        match self.is_root() {
+            true => None,
+            false => self.node.edge(),
+        }
+    }
+
+    /// The number of levels between the [Zipper]'s current position and the root.
+    pub fn depth(&self) -> usize {
+        self.ancestors().count()
+    }
+
+    /// The position of the current value amongst its siblings, or `None` at the root.
+    pub fn index(&self) -> Option<usize> {
+        self.index_in_parent
+    }
+
+    /// Whether the [Zipper] is positioned at the root / top of the tree.
+    pub fn is_root(&self) -> bool {
+        self.parent.is_none()
+    }
+
+    /// Whether the current value has no children, i.e. whether [down](#method.down) would fail.
+    pub fn is_leaf(&self) -> bool {
+        self.cycle().is_some()
+            || !self
+                .shared
+                .cache
+                .contains(&self.history.clone().step(Step::Down))
+                && self.shared.child(&self.node, 0).ok().flatten().is_none()
+    }
+
+    /// Whether the current value has a previous sibling, i.e. whether [left](#method.left) would succeed.
+    pub fn has_left(&self) -> bool {
+        matches!(self.index_in_parent, Some(index) if index > 0)
+    }
+
+    /// Whether the current value has a next sibling, i.e. whether [right](#method.right) would succeed.
+    pub fn has_right(&self) -> bool {
+        if self
+            .shared
+            .cache
+            .contains(&self.history.clone().step(Step::Right))
+        {
+            return true;
+        }
+
+        match (self.index_in_parent, self.parent.as_ref()) {
+            (Some(index), Some(parent)) => self
+                .shared
+                .child(&parent.node, index + 1)
+                .ok()
+                .flatten()
+                .is_some(),
+            _ => false,
+        }
+    }
+
+    /// The number of children of the current value, or `0` if they cannot be listed.
+    pub fn child_count(&self) -> usize {
+        self.shared.child_count(&self.node).unwrap_or(0)
+    }
+
+    /// Prints the tree explored so far to stderr, labelling each value by its [Debug] representation. See
+    /// [render](#method.render).
     pub fn show(self) -> Self
     where
-        Self: Debug,
+        T: Debug,
     {
-        dbg!("{:#?}", &self);
+        eprintln!("{}", self.render(|node| format!("{node:?}")));
+        self
+    }
+}
+
+/// Counters of how well the caches of a [ZipperSession] are working. See [ZipperSession::stats].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Moves to a position that was found in the cache of visited positions
+    pub hits: usize,
+    /// Moves to a position that was not found in the cache of visited positions
+    pub misses: usize,
+    /// The number of positions in the cache
+    pub entries: usize,
+    /// The number of calls to [Zippable::children], or [TryZippable::try_children] when errors are surfaced
+    pub children_calls: usize,
+}
+
+type Children<T> = std::iter::Enumerate<std::vec::IntoIter<T>>;
+
+/// An [Iterator] over [Zipper]s positioned at each descendant of a value. See [Zipper::descendants].
+pub struct Descendants<T>
+where
+    T: Zippable,
+{
+    expand: Option<Zipper<T>>,
+    stack: Vec<(Rc<Frame<T>>, History, Children<T>)>,
+    shared: Shared<T>,
+    cursor: Option<Rc<Cursor>>,
+    max_depth: Option<usize>,
+}
+
+impl<T> Descendants<T>
+where
+    T: Zippable,
+{
+    /// Stops descending `depth` levels below the starting position, so a depth of `1` yields only its children.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
         self
     }
 }
 
+impl<T> Iterator for Descendants<T>
+where
+    T: Zippable,
+{
+    type Item = Zipper<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the children of the previous item come before its siblings
+        if let Some(parent) = self
+            .expand
+            .take()
+            .filter(|_| self.max_depth.is_none_or(|depth| self.stack.len() < depth))
+        {
+            let children = self.shared.children(&parent.node).unwrap_or_default();
+            let frame = Rc::new(Frame {
+                node: parent.node,
+                hash: parent.hash,
+                index: parent.index_in_parent,
+                parent: parent.parent,
+            });
+            self.stack
+                .push((frame, parent.history, children.into_iter().enumerate()));
+        }
+
+        loop {
+            let (parent, history, children) = self.stack.last_mut()?;
+
+            let Some((index, node)) = children.next() else {
+                self.stack.pop();
+                continue;
+            };
+
+            let history = std::iter::once(Step::Down)
+                .chain(std::iter::repeat_n(Step::Right, index))
+                .fold(history.clone(), History::step);
+
+            let next = Zipper {
+                hash: self.shared.hash(&node),
+                node,
+                parent: Some(parent.clone()),
+                index_in_parent: Some(index),
+                history,
+                shared: self.shared.clone(),
+                cursor: None,
+            };
+
+            match next.cycle() {
+                Some(CyclePolicy::Error) => continue,
+                Some(CyclePolicy::Leaf) => {}
+                None => self.expand = Some(next.clone()),
+            }
+
+            return Some(Zipper {
+                cursor: self.cursor.clone(),
+                ..next
+            });
+        }
+    }
+}
+
+/// Creates and returns a [Zipper] over `root` and the values unfolded from it by `children`, which lists the
+/// children of a value, each time all at once. Suits types that cannot implement [Zippable] themselves, or trees that are generated
+/// rather than stored, such as the states of a game:
+///
+/// ```
+/// use zippered::zipper::*;
+///
+/// // a pile of stones, from which either one or two may be taken
+/// let zipper = from_fn(5, |&stones: &usize| (1..=stones.min(2)).map(move |take| stones - take));
+///
+/// assert_eq!(*zipper.clone().down()?.node, 4);
+/// assert_eq!(*zipper.down()?.right()?.down()?.node, 2);
+/// # Ok::<(), ZipperErr>(())
+/// ```
+pub fn from_fn<T, F, I>(root: T, children: F) -> Zipper<Unfold<T, F>>
+where
+    T: Clone,
+    F: Fn(&T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    Unfold::new(root, children).zipper()
+}
+
+/// A value along with the closure listing its children, as unfolded by [from_fn]. Dereferences to the value.
+pub struct Unfold<T, F> {
+    pub value: T,
+    children: Rc<F>,
+}
+
+impl<T, F> Unfold<T, F> {
+    /// Pairs `root` with the closure listing the children of each value, for when a [ZipperSession] rather than
+    /// a single [Zipper] is wanted. See [from_fn].
+    pub fn new(root: T, children: F) -> Self {
+        Unfold {
+            value: root,
+            children: Rc::new(children),
+        }
+    }
+}
+
+impl<T, F> Clone for Unfold<T, F>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Unfold {
+            value: self.value.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<T, F> Debug for Unfold<T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
+impl<T, F> Deref for Unfold<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, F, I> Zippable for Unfold<T, F>
+where
+    T: Clone,
+    F: Fn(&T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        // the closure's iterator may not outlive the borrow of `self`, so the children are listed up front
+        let children = (self.children)(&self.value).into_iter().collect::<Vec<_>>();
+
+        children.into_iter().map(|value| Unfold {
+            value,
+            children: self.children.clone(),
+        })
+    }
+}
+
 /// Represents a [Zipper]'s inability to move in a given direction.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum ZipperErr {
     CannotGoUp,
     CannotGoLeft,
     CannotGoRight,
     CannotGoDown,
     CannotGoBack,
+    UnknownBookmark,
+    CannotJump,
+    Cycle,
+    /// The children of the value at `path` could not be listed. See [TryZippable]
+    CannotListChildren {
+        path: Vec<Step>,
+        source: Arc<dyn Error + Send + Sync>,
+    },
+}
+
+impl std::fmt::Display for ZipperErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZipperErr::CannotGoUp => write!(f, "cannot go up"),
+            ZipperErr::CannotGoLeft => write!(f, "cannot go left"),
+            ZipperErr::CannotGoRight => write!(f, "cannot go right"),
+            ZipperErr::CannotGoDown => write!(f, "cannot go down"),
+            ZipperErr::CannotGoBack => write!(f, "cannot go back"),
+            ZipperErr::UnknownBookmark => write!(f, "unknown bookmark"),
+            ZipperErr::CannotJump => write!(f, "cannot jump to a bookmark under a different root"),
+            ZipperErr::Cycle => write!(f, "cycle detected"),
+            ZipperErr::CannotListChildren { path, source } => {
+                write!(f, "cannot list children at {path:?}: {source}")
+            }
+        }
+    }
+}
+
+impl Error for ZipperErr {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ZipperErr::CannotListChildren { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
 }
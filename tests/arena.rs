@@ -0,0 +1,129 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use zippered::arena::ArenaZipper;
+use zippered::zipper::{Step::*, *};
+
+/// A complete ternary tree numbered in breadth-first order, three levels deep, counting how often children are
+/// listed.
+#[derive(Debug, Clone)]
+struct Heap {
+    n: usize,
+    listed: Rc<Cell<usize>>,
+}
+
+impl Zippable for Heap {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.listed.set(self.listed.get() + 1);
+        let children = match self.n < 13 {
+            true => 3 * self.n - 1..3 * self.n + 2,
+            false => 0..0,
+        };
+        children.map(|n| Heap {
+            n,
+            listed: self.listed.clone(),
+        })
+    }
+}
+
+fn heap() -> Heap {
+    Heap {
+        n: 1,
+        listed: Rc::new(Cell::new(0)),
+    }
+}
+
+#[test]
+fn moves_like_a_zipper() {
+    let steps = [
+        Down, Right, Right, Right, Down, Left, Right, Down, Back, Back, Up, Left, Back, Up, Up,
+        Back, Down, Down, Down, Down, Up, Right, Back, Right, Left, Back,
+    ];
+    let (mut zipper, mut arena) = (heap().zipper(), ArenaZipper::new(heap()));
+
+    for step in steps {
+        let (moved, arena_moved) = (zipper.clone().step(&step), arena.clone().step(&step));
+
+        match (moved, arena_moved) {
+            (Ok(moved), Ok(arena_moved)) => (zipper, arena) = (moved, arena_moved),
+            (Err(err), Err(arena_err)) => assert_eq!(err.to_string(), arena_err.to_string()),
+            (moved, arena_moved) => panic!(
+                "{step:?}: {:?} but {:?}",
+                moved.is_ok(),
+                arena_moved.is_ok()
+            ),
+        }
+
+        assert_eq!(arena.node().n, zipper.node.n, "{step:?}");
+        assert_eq!(
+            arena.path().collect::<Vec<_>>(),
+            zipper.path().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            arena.journey().collect::<Vec<_>>(),
+            zipper.journey().collect::<Vec<_>>()
+        );
+        assert_eq!(arena.depth(), zipper.depth());
+        assert_eq!(arena.index(), zipper.index());
+        assert_eq!(arena.is_root(), zipper.is_root());
+    }
+}
+
+#[test]
+fn lists_children_once() -> Result<(), ZipperErr> {
+    let root = heap();
+    let arena = ArenaZipper::new(root.clone());
+    assert_eq!(arena.stored(), 1);
+
+    let arena = arena.travel([Down, Right, Down, Up, Left, Up, Down, Right, Down].into_iter())?;
+    assert_eq!(arena.node().n, 8);
+    assert_eq!(root.listed.get(), 2);
+    assert_eq!(arena.stored(), 7);
+
+    // clones share the arena
+    let clone = arena.clone().up()?.right()?.down()?;
+    assert_eq!(clone.node().n, 11);
+    assert_eq!(root.listed.get(), 3);
+    assert_eq!(arena.stored(), 10);
+
+    Ok(())
+}
+
+#[test]
+fn fails_at_the_edges() -> Result<(), ZipperErr> {
+    let root = ArenaZipper::new(heap());
+    assert!(matches!(root.clone().up(), Err(ZipperErr::CannotGoUp)));
+    assert!(matches!(root.clone().left(), Err(ZipperErr::CannotGoLeft)));
+    assert!(matches!(
+        root.clone().right(),
+        Err(ZipperErr::CannotGoRight)
+    ));
+    assert!(matches!(root.clone().back(), Err(ZipperErr::CannotGoBack)));
+    assert!(matches!(
+        root.clone().step(&UpTo(0)),
+        Err(ZipperErr::CannotGoUp)
+    ));
+
+    let leaf = root.travel([Down, Right, Right, Down, Right, Right].into_iter())?;
+    assert_eq!(leaf.node().n, 13);
+    assert_eq!(leaf.depth(), 2);
+    assert_eq!(leaf.index(), Some(2));
+    assert!(matches!(leaf.clone().down(), Err(ZipperErr::CannotGoDown)));
+    assert!(matches!(
+        leaf.clone().right(),
+        Err(ZipperErr::CannotGoRight)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn moves_while_holding_a_node() -> Result<(), ZipperErr> {
+    let arena = ArenaZipper::new(heap());
+    let root = arena.node();
+
+    let down = arena.clone().down()?;
+    assert_eq!(down.node().n, 2);
+    assert_eq!(root.n, 1);
+
+    Ok(())
+}
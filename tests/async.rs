@@ -0,0 +1,171 @@
+#![cfg(feature = "async")]
+
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use zippered::async_zipper::*;
+use zippered::zipper::{Step::*, ZipperErr};
+
+/// A stub object store, counting how many children it hands out.
+struct Store {
+    children: HashMap<usize, Vec<usize>>,
+    fetched: Cell<usize>,
+}
+
+#[derive(Clone)]
+struct Object {
+    id: usize,
+    store: Rc<Store>,
+}
+
+impl AsyncZippable for Object {
+    fn children(&self) -> impl Stream<Item = Self> + '_ {
+        let ids = self
+            .store
+            .children
+            .get(&self.id)
+            .cloned()
+            .unwrap_or_default();
+        stream::iter(ids).then(move |id| async move {
+            self.store.fetched.set(self.store.fetched.get() + 1);
+            Object {
+                id,
+                store: self.store.clone(),
+            }
+        })
+    }
+}
+
+fn root() -> Object {
+    let children = HashMap::from([(0, vec![1, 2, 3]), (1, vec![4]), (3, vec![5, 6])]);
+
+    Object {
+        id: 0,
+        store: Rc::new(Store {
+            children,
+            fetched: Cell::new(0),
+        }),
+    }
+}
+
+#[test]
+fn movement() {
+    block_on(async {
+        let zipper = root().zipper();
+
+        assert!(matches!(
+            zipper.clone().up().await,
+            Err(ZipperErr::CannotGoUp)
+        ));
+        assert!(matches!(
+            zipper.clone().left().await,
+            Err(ZipperErr::CannotGoLeft)
+        ));
+        assert!(matches!(
+            zipper.clone().right().await,
+            Err(ZipperErr::CannotGoRight)
+        ));
+        assert!(matches!(
+            zipper.clone().back().await,
+            Err(ZipperErr::CannotGoBack)
+        ));
+
+        let one = zipper.down().await?;
+        assert_eq!(one.node.id, 1);
+        assert_eq!(one.down().await?.node.id, 4);
+
+        let six = root()
+            .zipper()
+            .travel([Down, Right, Right, Down, Right].into_iter())
+            .await?;
+        assert_eq!(six.node.id, 6);
+        assert_eq!(six.depth(), 2);
+        assert_eq!(six.index(), Some(1));
+        assert_eq!(
+            six.path().collect::<Vec<_>>(),
+            vec![Down, Right, Right, Down, Right]
+        );
+
+        let three = six.up().await?;
+        assert_eq!(three.node.id, 3);
+        assert_eq!(three.path().collect::<Vec<_>>(), vec![Down, Right, Right]);
+        assert!(matches!(
+            three.clone().right().await,
+            Err(ZipperErr::CannotGoRight)
+        ));
+        assert_eq!(three.left().await?.node.id, 2);
+
+        Ok::<_, ZipperErr>(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn back_and_journey() {
+    block_on(async {
+        let zipper = root()
+            .zipper()
+            .travel([Down, Right, Right, Down].into_iter())
+            .await?;
+        assert_eq!(zipper.node.id, 5);
+
+        let back = zipper.back().await?.back().await?;
+        assert_eq!(back.node.id, 2);
+        assert!(!back.is_root());
+        assert_eq!(back.path().collect::<Vec<_>>(), vec![Down, Right]);
+        assert_eq!(
+            back.journey().collect::<Vec<_>>(),
+            vec![Down, Right, Right, Down, Back, Back]
+        );
+
+        let root = back.back().await?.back().await?;
+        assert!(root.is_root());
+        assert_eq!(root.node.id, 0);
+        assert!(matches!(root.back().await, Err(ZipperErr::CannotGoBack)));
+
+        Ok::<_, ZipperErr>(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn cached() {
+    block_on(async {
+        let root = root();
+        let zipper = root.zipper().down().await?.right().await?.right().await?;
+        let fetched = root.store.fetched.get();
+
+        // moving over visited positions fetches nothing
+        let zipper = zipper.left().await?.left().await?.right().await?;
+        assert_eq!(zipper.node.id, 2);
+        assert_eq!(root.store.fetched.get(), fetched);
+
+        // nor does revisiting a child
+        let zipper = zipper.up().await?.down().await?;
+        assert_eq!(zipper.node.id, 1);
+        assert_eq!(root.store.fetched.get(), fetched);
+
+        Ok::<_, ZipperErr>(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn frees_visited_positions() {
+    let root = root();
+
+    block_on(async {
+        let zipper = root
+            .zipper()
+            .travel([Down, Right, Right, Down].into_iter())
+            .await?;
+        assert_eq!(zipper.back().await?.node.id, 3);
+        Ok::<_, ZipperErr>(())
+    })
+    .unwrap();
+
+    // the cache of visited positions goes with the last zipper sharing it
+    assert_eq!(Rc::strong_count(&root.store), 1);
+}
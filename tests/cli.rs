@@ -0,0 +1,110 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+
+const DOCUMENT: &str =
+    r#"{ "users": [{ "name": "ada" }, { "name": "grace", "langs": ["en", "fr"] }] }"#;
+
+/// Runs the CLI with `args` over a file holding [DOCUMENT], returning its exit code, stdout and stderr.
+fn zippered(args: &[&str]) -> (i32, String, String) {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("users.json");
+    std::fs::write(&path, DOCUMENT).unwrap();
+
+    let mut command = Command::new(env!("CARGO_BIN_EXE_zippered"));
+    match args {
+        [subcommand, rest @ ..] => command.arg(subcommand).arg(&path).args(rest),
+        [] => &mut command,
+    };
+    let output = command.output().unwrap();
+
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn get_by_steps() {
+    assert_eq!(
+        zippered(&["get", "D D R D R"]),
+        (0, "\"grace\"\n".into(), String::new())
+    );
+    assert_eq!(
+        zippered(&["get", "DDRDDR"]),
+        (0, "\"fr\"\n".into(), String::new())
+    );
+    assert_eq!(
+        zippered(&["get", "D D R D B L"]),
+        (0, "{\"name\":\"ada\"}\n".into(), String::new())
+    );
+    assert!(zippered(&["get", ""])
+        .1
+        .starts_with("{\"users\":[{\"name\":\"ada\"}"));
+}
+
+#[test]
+fn get_by_pointer() {
+    assert_eq!(
+        zippered(&["get", "/users/1/langs/0"]),
+        (0, "\"en\"\n".into(), String::new())
+    );
+    assert_eq!(
+        zippered(&["get", "/users/0"]),
+        (0, "{\"name\":\"ada\"}\n".into(), String::new())
+    );
+}
+
+#[test]
+fn get_failures() {
+    assert_eq!(
+        zippered(&["get", "D R"]),
+        (1, String::new(), "zippered: cannot go right\n".into())
+    );
+    assert_eq!(
+        zippered(&["get", "D X"]),
+        (
+            1,
+            String::new(),
+            "zippered: unknown step 'X', expected one of U, D, L, R or B\n".into()
+        )
+    );
+    assert_eq!(
+        zippered(&["get", "/users/2"]),
+        (
+            1,
+            String::new(),
+            "zippered: no value at \"/users/2\"\n".into()
+        )
+    );
+}
+
+#[test]
+fn paths() {
+    let (code, output, _) = zippered(&["paths"]);
+
+    assert_eq!(code, 0);
+    assert_eq!(
+        output,
+        "\
+D\t/users
+D D\t/users/0
+D D D\t/users/0/name
+D D R\t/users/1
+D D R D\t/users/1/langs
+D D R D D\t/users/1/langs/0
+D D R D D R\t/users/1/langs/1
+D D R D R\t/users/1/name
+"
+    );
+}
+
+#[test]
+fn usage() {
+    let (code, output, error) = zippered(&[]);
+
+    assert_eq!(code, 2);
+    assert!(output.is_empty());
+    assert!(error.starts_with("usage: zippered get"));
+}
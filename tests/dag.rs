@@ -0,0 +1,193 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use zippered::zipper::{Step::*, *};
+
+/// A lattice where every node below `size` has the children `value + 1` and `value + 2`, so most nodes are
+/// reachable by many paths.
+#[derive(Debug, Clone)]
+struct Lattice {
+    value: usize,
+    size: usize,
+    calls: Rc<RefCell<HashMap<usize, usize>>>,
+}
+
+impl Lattice {
+    fn new(size: usize) -> Self {
+        Self {
+            value: 0,
+            size,
+            calls: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    fn calls(&self) -> Vec<usize> {
+        let calls = self.calls.borrow();
+        (0..=self.size)
+            .map(|value| calls.get(&value).cloned().unwrap_or(0))
+            .collect()
+    }
+}
+
+impl Zippable for Lattice {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        *self.calls.borrow_mut().entry(self.value).or_default() += 1;
+
+        [self.value + 1, self.value + 2]
+            .into_iter()
+            .filter(|value| *value <= self.size)
+            .map(|value| Lattice {
+                value,
+                ..self.clone()
+            })
+    }
+
+    fn parents(&self) -> impl Iterator<Item = Self> + '_ {
+        [self.value.checked_sub(1), self.value.checked_sub(2)]
+            .into_iter()
+            .flatten()
+            .map(|value| Lattice {
+                value,
+                ..self.clone()
+            })
+    }
+}
+
+impl Identified for Lattice {
+    type Id = usize;
+
+    fn id(&self) -> Self::Id {
+        self.value
+    }
+}
+
+#[test]
+fn children_computed_once_per_node() {
+    let lattice = Lattice::new(6);
+
+    let values = lattice
+        .session()
+        .cache_children_by_id()
+        .cursor()
+        .descendants()
+        .map(|zipped| zipped.node.value)
+        .collect::<Vec<_>>();
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 and every shortcut along the way
+    assert_eq!(values.len(), 32);
+    assert_eq!(lattice.calls(), vec![1; 7]);
+}
+
+#[test]
+fn children_computed_per_path_without_identity() {
+    let lattice = Lattice::new(6);
+
+    assert_eq!(lattice.zipper().descendants().count(), 32);
+    assert_eq!(lattice.calls(), vec![1, 1, 2, 3, 5, 8, 13]);
+}
+
+#[test]
+fn movement_by_identity() -> Result<(), ZipperErr> {
+    let lattice = Lattice::new(4);
+    let session = lattice.session().cache_children_by_id();
+
+    // reach 2 directly, then by way of 1
+    let direct = session.cursor().down()?.right()?;
+    let indirect = session.cursor().down()?.down()?;
+
+    assert_eq!(direct.node.value, 2);
+    assert_eq!(indirect.node.value, 2);
+    assert_eq!(direct.clone().down()?.right()?.node.value, 4);
+    assert_eq!(indirect.clone().down()?.right()?.node.value, 4);
+    assert_eq!(indirect.path().collect::<Vec<Step>>(), vec![Down, Down]);
+    assert!(direct.has_left());
+    assert_eq!(direct.child_count(), 2);
+    assert_eq!(lattice.calls(), vec![1, 1, 1, 0, 0]);
+
+    // left still works by path
+    assert_eq!(direct.left()?.node.value, 1);
+
+    Ok(())
+}
+
+#[test]
+fn up_to_another_parent() -> Result<(), ZipperErr> {
+    let lattice = Lattice::new(6);
+
+    // reach 3 by way of 1 and 2
+    let three = lattice.zipper().down()?.down()?.down()?;
+    assert_eq!(three.node.value, 3);
+    assert_eq!(
+        three
+            .parents()
+            .map(|parent| parent.value)
+            .collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+
+    // pivot to the parent we did not come from
+    let one = three.up_to(|parent| parent.value == 1)?;
+    assert_eq!(one.node.value, 1);
+    assert!(one.is_root());
+    assert_eq!(one.path().collect::<Vec<Step>>(), vec![]);
+    assert_eq!(
+        one.journey().collect::<Vec<Step>>(),
+        vec![Down, Down, Down, UpTo(1)]
+    );
+    assert!(one.clone().up().is_err());
+
+    // paths now lead from the new root
+    let four = one.down()?.right()?.down()?;
+    assert_eq!(four.node.value, 4);
+    assert_eq!(four.path().collect::<Vec<Step>>(), vec![Down, Right, Down]);
+
+    // the journey can be replayed from the original root
+    assert_eq!(
+        lattice.zipper().travel(four.journey())?.node.value,
+        four.node.value
+    );
+
+    Ok(())
+}
+
+#[test]
+fn up_to_without_parents() -> Result<(), ZipperErr> {
+    let lattice = Lattice::new(6);
+
+    assert!(matches!(
+        lattice.zipper().up_to(|_| true),
+        Err(ZipperErr::CannotGoUp)
+    ));
+    assert!(matches!(
+        lattice.zipper().down()?.up_to(|parent| parent.value == 5),
+        Err(ZipperErr::CannotGoUp)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn up_to_keeps_roots_apart() -> Result<(), ZipperErr> {
+    let lattice = Lattice::new(6);
+    let session = lattice.session();
+
+    let two = session.cursor().down()?.right()?;
+    two.mark("two");
+
+    // 3 and its own children are reached at the same paths as 0 and its children were
+    let three = two.down()?.down()?.up_to(|parent| parent.value == 3)?;
+    assert_eq!(three.clone().down()?.node.value, 4);
+    assert_eq!(three.clone().down()?.right()?.node.value, 5);
+    assert_eq!(session.cursor().down()?.node.value, 1);
+    assert_eq!(session.cursor().down()?.right()?.node.value, 2);
+
+    // bookmarks under the original root cannot be reached
+    assert!(matches!(
+        three.clone().jump("two"),
+        Err(ZipperErr::CannotJump)
+    ));
+
+    // further cursors start from the new root
+    let another = three.session().cursor().down()?;
+    assert_eq!(another.node.value, 4);
+
+    Ok(())
+}
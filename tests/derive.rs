@@ -0,0 +1,143 @@
+#![cfg(feature = "derive")]
+
+use std::rc::Rc;
+use zippered::zipper::{Step::*, *};
+
+#[derive(Debug, Clone, PartialEq, Eq, Zippable)]
+enum Tree {
+    Node(usize),
+    #[zippable(children)]
+    Branch(Vec<Tree>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Zippable)]
+enum Expr {
+    Literal(i64),
+    Negate(#[zippable(children)] Box<Expr>),
+    #[zippable(children)]
+    Add(Box<Expr>, Box<Expr>),
+    If {
+        #[zippable(children)]
+        condition: Rc<Expr>,
+        #[zippable(children)]
+        then: Box<Expr>,
+        #[zippable(children)]
+        otherwise: Option<Box<Expr>>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Zippable)]
+struct Section<T> {
+    title: T,
+    #[zippable(children)]
+    lead: Option<Box<Section<T>>>,
+    #[zippable(children)]
+    sections: Vec<Section<T>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Zippable)]
+struct Leaf;
+
+#[derive(Debug, Clone, PartialEq, Eq, Zippable)]
+#[zippable(children)]
+struct Forest(Vec<Forest>);
+
+fn section(title: &'static str, sections: Vec<Section<&'static str>>) -> Section<&'static str> {
+    Section {
+        title,
+        lead: None,
+        sections,
+    }
+}
+
+#[test]
+fn enum_with_vec() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![
+        Tree::Node(1),
+        Tree::Branch(vec![Tree::Node(2), Tree::Node(3)]),
+    ]);
+
+    assert_eq!(Tree::Node(1).children().count(), 0);
+    assert_eq!(
+        tree.children().collect::<Vec<_>>(),
+        vec![
+            Tree::Node(1),
+            Tree::Branch(vec![Tree::Node(2), Tree::Node(3)])
+        ]
+    );
+    assert_eq!(
+        tree.zipper()
+            .travel([Down, Right, Down, Right].into_iter())?
+            .node,
+        Tree::Node(3)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn enum_with_boxes_and_fields() {
+    let one = Expr::Literal(1);
+    let two = Expr::Negate(Box::new(Expr::Literal(2)));
+    let sum = Expr::Add(Box::new(one.clone()), Box::new(two.clone()));
+
+    assert_eq!(one.children().count(), 0);
+    assert_eq!(two.children().collect::<Vec<_>>(), vec![Expr::Literal(2)]);
+    assert_eq!(
+        sum.children().collect::<Vec<_>>(),
+        vec![one.clone(), two.clone()]
+    );
+
+    let without = Expr::If {
+        condition: Rc::new(one.clone()),
+        then: Box::new(two.clone()),
+        otherwise: None,
+    };
+    assert_eq!(
+        without.children().collect::<Vec<_>>(),
+        vec![one.clone(), two.clone()]
+    );
+
+    let with = Expr::If {
+        condition: Rc::new(one.clone()),
+        then: Box::new(two.clone()),
+        otherwise: Some(Box::new(sum.clone())),
+    };
+    assert_eq!(with.children().collect::<Vec<_>>(), vec![one, two, sum]);
+}
+
+#[test]
+fn generic_struct() -> Result<(), ZipperErr> {
+    let mut book = section("book", vec![section("one", vec![]), section("two", vec![])]);
+    book.lead = Some(Box::new(section("preface", vec![])));
+
+    let titles = book
+        .zipper()
+        .descendants()
+        .map(|zipped| zipped.node.title)
+        .collect::<Vec<_>>();
+    assert_eq!(titles, vec!["preface", "one", "two"]);
+
+    assert_eq!(book.zipper().down()?.right()?.right()?.node.title, "two");
+
+    Ok(())
+}
+
+#[test]
+fn unit_struct() {
+    assert_eq!(Leaf.children().count(), 0);
+    assert!(Leaf.zipper().is_leaf());
+}
+
+#[test]
+fn marked_struct() -> Result<(), ZipperErr> {
+    let forest = Forest(vec![Forest(vec![]), Forest(vec![Forest(vec![])])]);
+
+    assert_eq!(forest.children().count(), 2);
+    assert_eq!(
+        forest.zipper().down()?.right()?.down()?.node,
+        Forest(vec![])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "explore")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Runs the explorer over `path`, scripted by `keys`, returning its exit status and output.
+fn explore(path: &std::path::Path, keys: &str) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_zippered-explore"))
+        .arg(path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(keys.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn json() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("users.json");
+    std::fs::write(
+        &path,
+        r#"{ "users": [{ "name": "ada" }, { "name": "grace" }], "count": 2 }"#,
+    )
+    .unwrap();
+
+    let (success, output) = explore(&path, "jl\nj l\nx\nkkk\nb\nq\nj\n");
+
+    assert!(success);
+    assert_eq!(
+        output,
+        "\
+focus: {2 fields}
+path: []
+children:
+  count: 2
+  users: [2 items]
+
+focus: users: [2 items]
+path: [Down, Right]
+children:
+  0: {1 fields}
+  1: {1 fields}
+
+focus: 1: {1 fields}
+path: [Down, Right, Down, Right]
+children:
+  name: \"grace\"
+
+unknown key 'x', keys: h left, j down, k up, l right, b back, q quit
+cannot go up
+focus: 1: {1 fields}
+path: [Down, Right, Down, Right]
+children:
+  name: \"grace\"
+
+focus: 0: {1 fields}
+path: [Down, Right, Down]
+children:
+  name: \"ada\"
+
+"
+    );
+}
+
+#[test]
+fn directory() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src").join("lib.rs"), "").unwrap();
+    std::fs::write(dir.path().join("Cargo.toml"), "").unwrap();
+
+    let (success, output) = explore(dir.path(), "jl\n");
+
+    assert!(success);
+    assert!(output.ends_with(
+        "\
+focus: src/
+path: [Down, Right]
+children:
+  lib.rs
+
+"
+    ));
+    assert!(output.contains("children:\n  Cargo.toml\n  src/\n"));
+}
+
+#[test]
+fn invalid_json() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("broken.json");
+    std::fs::write(&path, "{").unwrap();
+
+    let (success, output) = explore(&path, "");
+
+    assert!(!success);
+    assert!(output.is_empty());
+}
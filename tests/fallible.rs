@@ -0,0 +1,127 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use zippered::zipper::{Step::*, *};
+
+/// A node of a remote tree, where listing the children of node `2` fails.
+#[derive(Debug, Clone)]
+struct Remote(usize);
+
+#[derive(Debug)]
+struct Offline(usize);
+
+impl Display for Offline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node {} is offline", self.0)
+    }
+}
+
+impl Error for Offline {}
+
+impl TryZippable for Remote {
+    type Error = Offline;
+
+    fn try_children(&self) -> Result<impl Iterator<Item = Self> + '_, Self::Error> {
+        match self.0 {
+            2 => Err(Offline(2)),
+            n if n < 10 => Ok(vec![Remote(n * 10 + 1), Remote(n * 10 + 2)].into_iter()),
+            _ => Ok(vec![].into_iter()),
+        }
+    }
+}
+
+impl Zippable for Remote {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.try_children().into_iter().flatten()
+    }
+}
+
+impl Identified for Remote {
+    type Id = usize;
+
+    fn id(&self) -> Self::Id {
+        self.0
+    }
+}
+
+#[test]
+fn surfaced() -> Result<(), ZipperErr> {
+    let zipper = Remote(0).try_zipper();
+
+    assert_eq!(
+        zipper
+            .clone()
+            .travel([Down, Down, Right].into_iter())?
+            .node
+            .0,
+        12
+    );
+
+    let Err(err) = zipper.travel([Down, Right, Down].into_iter()) else {
+        panic!("expected an error");
+    };
+    match &err {
+        ZipperErr::CannotListChildren { path, source } => {
+            assert_eq!(path, &vec![Down, Right]);
+            assert_eq!(source.to_string(), "node 2 is offline");
+        }
+        other => panic!("unexpected {other:?}"),
+    }
+    assert_eq!(
+        err.to_string(),
+        "cannot list children at [Down, Right]: node 2 is offline"
+    );
+    assert!(err.source().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn hidden() -> Result<(), ZipperErr> {
+    let zipped = Remote(0).zipper().down()?.right()?;
+
+    assert!(matches!(zipped.down(), Err(ZipperErr::CannotGoDown)));
+
+    Ok(())
+}
+
+#[test]
+fn surfaced_with_children_cache() -> Result<(), ZipperErr> {
+    let session = Remote(0).session().surface_errors().cache_children_by_id();
+    let zipped = session.cursor().down()?.right()?;
+
+    // errors are not cached, so every attempt reports them
+    for _ in 0..2 {
+        assert!(matches!(
+            zipped.clone().down(),
+            Err(ZipperErr::CannotListChildren { .. })
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn queries_treat_errors_as_leaves() -> Result<(), ZipperErr> {
+    let zipper = Remote(0).try_zipper();
+    let zipped = zipper.clone().down()?.right()?;
+
+    assert!(zipped.is_leaf());
+    assert_eq!(zipped.child_count(), 0);
+
+    let values = zipper
+        .descendants()
+        .map(|zipped| zipped.node.0)
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![1, 11, 12, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn errors_are_send_and_sync() {
+    fn fails() -> Result<(), Box<dyn Error + Send + Sync>> {
+        Err(ZipperErr::CannotGoUp)?
+    }
+
+    assert_eq!(fails().unwrap_err().to_string(), "cannot go up");
+}
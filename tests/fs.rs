@@ -0,0 +1,134 @@
+#![cfg(feature = "fs")]
+
+use std::fs;
+use tempfile::TempDir;
+use zippered::fs::{FsNode, Symlinks};
+use zippered::zipper::{Step::*, *};
+
+/// Builds a directory tree from relative file paths, creating any directories along the way.
+fn temp_tree(files: &[&str]) -> TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    for file in files {
+        let path = dir.path().join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, file).unwrap();
+    }
+    dir
+}
+
+fn names(zipper: &Zipper<FsNode>) -> Vec<String> {
+    zipper
+        .descendants()
+        .map(|zipped| {
+            let path = zipped.node.path().to_path_buf();
+            let root = zipper.node.path();
+            path.strip_prefix(root).unwrap().display().to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn sorted_entries() -> Result<(), ZipperErr> {
+    let dir = temp_tree(&["b/2.txt", "b/1.txt", "a.txt", "c/d/e.txt"]);
+    let zipper = FsNode::new(dir.path()).zipper();
+
+    assert_eq!(
+        names(&zipper),
+        vec!["a.txt", "b", "b/1.txt", "b/2.txt", "c", "c/d", "c/d/e.txt"]
+    );
+
+    let zipped = zipper.travel([Down, Right, Down, Right].into_iter())?;
+    assert_eq!(zipped.node.name().unwrap(), "2.txt");
+    assert_eq!(zipped.edge().unwrap(), "2.txt");
+
+    // the edge was recorded on the way, so new entries do not shift it
+    fs::write(dir.path().join("b/0.txt"), "b/0.txt").unwrap();
+    assert_eq!(zipped.edge().unwrap(), "2.txt");
+    assert_eq!(
+        zipped.node.metadata().unwrap().len(),
+        "b/2.txt".len() as u64
+    );
+    assert!(zipped.is_leaf());
+    assert!(!zipped.up()?.node.metadata().unwrap().is_file());
+
+    Ok(())
+}
+
+#[test]
+fn ignore_globs() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = temp_tree(&[
+        "keep.txt",
+        "skip.log",
+        "target/out.txt",
+        "src/target/keep.txt",
+    ]);
+    let root = FsNode::new(dir.path()).ignore("*.log")?.ignore("target")?;
+
+    assert_eq!(
+        names(&root.zipper()),
+        vec!["keep.txt", "src", "src/target", "src/target/keep.txt"]
+    );
+
+    assert!(FsNode::new(dir.path()).ignore("[").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn parents() -> Result<(), ZipperErr> {
+    let dir = temp_tree(&["a/b.txt"]);
+    let zipper = FsNode::new(dir.path().join("a")).zipper();
+
+    let zipped = zipper.up_to(|parent| parent.path() == dir.path())?;
+    assert_eq!(zipped.node.path(), dir.path());
+    assert_eq!(zipped.down()?.node.name().unwrap(), "a");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn symlinks() -> Result<(), ZipperErr> {
+    let dir = temp_tree(&["a/file.txt"]);
+    std::os::unix::fs::symlink(dir.path().join("a"), dir.path().join("link")).unwrap();
+    let root = FsNode::new(dir.path());
+
+    assert_eq!(names(&root.zipper()), vec!["a", "a/file.txt", "link"]);
+
+    let followed = root.clone().symlinks(Symlinks::Follow).zipper();
+    assert_eq!(
+        names(&followed),
+        vec!["a", "a/file.txt", "link", "link/file.txt"]
+    );
+    assert!(followed.down()?.right()?.node.is_dir());
+
+    let skipped = root.symlinks(Symlinks::Skip).zipper();
+    assert_eq!(names(&skipped), vec!["a", "a/file.txt"]);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_cycle() {
+    let dir = temp_tree(&["a/file.txt"]);
+    std::os::unix::fs::symlink(dir.path(), dir.path().join("a/up")).unwrap();
+
+    let root = FsNode::new(dir.path()).symlinks(Symlinks::Follow);
+    let zipper = root.session().detect_cycles(CyclePolicy::Leaf).cursor();
+
+    assert_eq!(names(&zipper), vec!["a", "a/file.txt", "a/up"]);
+    assert!(zipper
+        .travel([Down, Down, Right].into_iter())
+        .unwrap()
+        .is_leaf());
+}
+
+#[test]
+fn missing() {
+    let dir = tempfile::tempdir().unwrap();
+    let zipper = FsNode::new(dir.path().join("missing")).zipper();
+
+    assert!(zipper.is_leaf());
+    assert!(zipper.node.metadata().is_err());
+}
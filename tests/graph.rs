@@ -1,15 +1,26 @@
-use petgraph::{graph::NodeIndex, EdgeDirection::Outgoing, Graph};
-use zippered::zipper::{Zippable, ZipperErr};
+use petgraph::{graph::NodeIndex, visit::EdgeRef, EdgeDirection::Outgoing, Graph};
+use std::cell::Cell;
+use zippered::zipper::{CyclePolicy, Edged, Identified, Zippable, ZipperErr, ZipperSession};
+
+thread_local! {
+    /// How often the current test has asked a node for its id.
+    static IDS: Cell<usize> = const { Cell::new(0) };
+}
 
 #[derive(Debug, Clone)]
 struct ZippableGraph<'g> {
     graph: &'g Graph<usize, usize, petgraph::Directed>,
     node_idx: NodeIndex,
+    edge: Option<usize>,
 }
 
 impl<'g> ZippableGraph<'g> {
     fn new(graph: &'g Graph<usize, usize, petgraph::Directed>, node_idx: NodeIndex) -> Self {
-        Self { graph, node_idx }
+        Self {
+            graph,
+            node_idx,
+            edge: None,
+        }
     }
 
     fn value(&self) -> usize {
@@ -21,8 +32,11 @@ impl<'g> Zippable for ZippableGraph<'g> {
     fn children(&self) -> impl Iterator<Item = Self> + '_ {
         Box::new(
             self.graph
-                .neighbors_directed(self.node_idx, Outgoing)
-                .map(|node| ZippableGraph::new(self.graph, node))
+                .edges_directed(self.node_idx, Outgoing)
+                .map(|edge| ZippableGraph {
+                    edge: Some(*edge.weight()),
+                    ..ZippableGraph::new(self.graph, edge.target())
+                })
                 // should not be necessary to do this normally, but neighbors are iterated in reverse-add order
                 // in petgraph, so we collect and reverse again here to make the tests easier to follow
                 .collect::<Vec<_>>()
@@ -32,6 +46,23 @@ impl<'g> Zippable for ZippableGraph<'g> {
     }
 }
 
+impl<'g> Edged for ZippableGraph<'g> {
+    type Edge = usize;
+
+    fn edge(&self) -> Option<Self::Edge> {
+        self.edge
+    }
+}
+
+impl<'g> Identified for ZippableGraph<'g> {
+    type Id = NodeIndex;
+
+    fn id(&self) -> Self::Id {
+        IDS.set(IDS.get() + 1);
+        self.node_idx
+    }
+}
+
 #[test]
 fn down() -> Result<(), ZipperErr> {
     let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
@@ -222,3 +253,153 @@ fn down_left_fail() -> Result<(), ZipperErr> {
     assert!(result.is_err());
     Ok(())
 }
+
+#[test]
+fn cycle_error() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    graph.extend_with_edges([(root, one), (one, two), (one, root), (two, one)]);
+
+    let zippable = ZippableGraph::new(&graph, root);
+    let zipper = zippable
+        .session()
+        .detect_cycles(CyclePolicy::Error)
+        .cursor();
+
+    let two = zipper.down()?.down()?;
+    assert_eq!(two.node.value(), 2);
+    assert!(matches!(two.clone().right(), Err(ZipperErr::Cycle)));
+    assert!(matches!(two.down(), Err(ZipperErr::Cycle)));
+
+    Ok(())
+}
+
+#[test]
+fn cycle_leaf() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    graph.extend_with_edges([(root, one), (one, root)]);
+
+    let zippable = ZippableGraph::new(&graph, root);
+    let zipper = zippable.session().detect_cycles(CyclePolicy::Leaf).cursor();
+
+    let repeated = zipper.down()?.down()?;
+    assert_eq!(repeated.node.value(), 0);
+    assert!(repeated.is_leaf());
+    assert!(matches!(repeated.down(), Err(ZipperErr::CannotGoDown)));
+
+    Ok(())
+}
+
+#[test]
+fn cycle_self_loop() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
+    let root = graph.add_node(0);
+    graph.extend_with_edges([(root, root)]);
+
+    let zippable = ZippableGraph::new(&graph, root);
+
+    let error = zippable
+        .session()
+        .detect_cycles(CyclePolicy::Error)
+        .cursor();
+    assert!(matches!(error.down(), Err(ZipperErr::Cycle)));
+
+    let leaf = zippable.session().detect_cycles(CyclePolicy::Leaf).cursor();
+    assert!(leaf.down()?.is_leaf());
+
+    Ok(())
+}
+
+#[test]
+fn cycle_ids_once_per_node() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
+    let nodes = (0..20).map(|n| graph.add_node(n)).collect::<Vec<_>>();
+    graph.extend_with_edges(nodes.windows(2).map(|pair| (pair[0], pair[1])));
+
+    let zippable = ZippableGraph::new(&graph, nodes[0]);
+    let mut zipper = zippable
+        .session()
+        .detect_cycles(CyclePolicy::Error)
+        .cursor();
+    while !zipper.is_leaf() {
+        zipper = zipper.down()?;
+    }
+
+    // checking each node against its ancestors does not ask them for their ids again
+    assert_eq!(zipper.node.value(), 19);
+    assert_eq!(IDS.get(), 20);
+
+    Ok(())
+}
+
+#[test]
+fn descendants_with_cycles() {
+    let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    let three = graph.add_node(3);
+    graph.extend_with_edges([
+        (root, one),
+        (one, root),
+        (one, two),
+        (two, one),
+        (root, three),
+    ]);
+
+    let zippable = ZippableGraph::new(&graph, root);
+
+    let values = |policy| {
+        zippable
+            .session()
+            .detect_cycles(policy)
+            .cursor()
+            .descendants()
+            .map(|zipped| zipped.node.value())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(values(CyclePolicy::Error), vec![1, 2, 3]);
+    assert_eq!(values(CyclePolicy::Leaf), vec![1, 0, 2, 1, 3]);
+}
+
+#[test]
+fn edge() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize, petgraph::Directed>::new();
+    let root = graph.add_node(0);
+    let parent = graph.add_node(42);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    graph.extend_with_edges([(root, parent, 10), (parent, one, 20), (parent, two, 30)]);
+
+    let zippable = ZippableGraph::new(&graph, root);
+
+    let zipper = zippable.zipper();
+    assert_eq!(zipper.edge(), None);
+
+    let zipper = zipper.down()?;
+    assert_eq!(zipper.edge(), Some(10));
+
+    let zipper = zipper.down()?.right()?;
+    assert_eq!(zipper.edge(), Some(30));
+
+    let zipper = zipper.left()?;
+    assert_eq!(zipper.edge(), Some(20));
+
+    let zipper = zipper.up()?;
+    assert_eq!(zipper.edge(), Some(10));
+
+    // edges are carried through the cache
+    let zipper = zipper.down()?.right()?.back()?;
+    assert_eq!(zipper.edge(), Some(20));
+
+    // and by a session that is not the node's own
+    let zipper = ZipperSession::new(zippable).cursor().down()?;
+    assert_eq!(zipper.edge(), Some(10));
+
+    Ok(())
+}
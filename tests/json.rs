@@ -0,0 +1,97 @@
+#![cfg(feature = "json")]
+
+use serde_json::{json, Value};
+use zippered::json::{JsonNode, Key};
+use zippered::zipper::{Step::*, *};
+
+fn document() -> Value {
+    json!({
+        "name": "root",
+        "items": [1, { "a/b": true, "c~d": null }, [2, 3]],
+        "empty": {}
+    })
+}
+
+#[test]
+fn keys_and_pointers() -> Result<(), ZipperErr> {
+    let document = document();
+    let zipper = JsonNode::new(&document).zipper();
+
+    assert_eq!(zipper.node.key(), None);
+    assert_eq!(zipper.node.pointer(), "");
+
+    // objects iterate their keys in order
+    let empty = zipper.clone().down()?;
+    assert_eq!(empty.node.key(), Some(Key::Field("empty")));
+    assert_eq!(empty.edge(), Some(Key::Field("empty")));
+    assert!(empty.is_leaf());
+
+    let object = empty.right()?.down()?.right()?;
+    assert_eq!(object.node.key(), Some(Key::Index(1)));
+    assert_eq!(object.node.pointer(), "/items/1");
+
+    let escaped = object.down()?;
+    assert_eq!(escaped.node.value(), &json!(true));
+    assert_eq!(escaped.node.pointer(), "/items/1/a~1b");
+    assert_eq!(escaped.right()?.node.pointer(), "/items/1/c~0d");
+
+    Ok(())
+}
+
+#[test]
+fn pointer_to_steps() -> Result<(), ZipperErr> {
+    let document = document();
+    let root = JsonNode::new(&document);
+
+    assert_eq!(root.steps_to(""), Some(vec![]));
+    assert_eq!(root.steps_to("/name"), Some(vec![Down, Right, Right]));
+    assert_eq!(
+        root.steps_to("/items/2/1"),
+        Some(vec![Down, Right, Down, Right, Right, Down, Right])
+    );
+
+    for pointer in ["/items/1/a~1b", "/items/1/c~0d", "/items/2/0", "/empty"] {
+        let steps = root.steps_to(pointer).unwrap();
+        let zipped = root.zipper().travel(steps.into_iter())?;
+        assert_eq!(zipped.node.pointer(), pointer);
+        assert_eq!(Some(zipped.node.value()), document.pointer(pointer));
+    }
+
+    // relative to the focus
+    let items = root.zipper().down()?.right()?;
+    let steps = items.node.steps_to("/2/1").unwrap();
+    assert_eq!(items.travel(steps.into_iter())?.node.value(), &json!(3));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_pointers() {
+    let document = document();
+    let root = JsonNode::new(&document);
+
+    for pointer in [
+        "name",
+        "/missing",
+        "/items/3",
+        "/items/01",
+        "/items/-",
+        "/items/+1",
+        "/name/0",
+    ] {
+        assert_eq!(root.steps_to(pointer), None, "{pointer}");
+    }
+}
+
+#[test]
+fn descendants() {
+    let document = json!([[1, 2], { "x": 3 }]);
+
+    let pointers = JsonNode::new(&document)
+        .zipper()
+        .descendants()
+        .map(|zipped| zipped.node.pointer())
+        .collect::<Vec<_>>();
+
+    assert_eq!(pointers, vec!["/0", "/0/0", "/0/1", "/1", "/1/x"]);
+}
@@ -0,0 +1,98 @@
+#![cfg(any(feature = "xml", feature = "html"))]
+
+use zippered::markup::*;
+use zippered::zipper::{Step::*, *};
+
+#[cfg(feature = "xml")]
+mod xml {
+    use super::*;
+    use roxmltree::Document;
+
+    const XML: &str = r#"<book lang="en"><title>Zippers</title><!-- draft --><chapter n="1">Intro <em>here</em></chapter></book>"#;
+
+    #[test]
+    fn elements() -> Result<(), ZipperErr> {
+        let document = Document::parse(XML).unwrap();
+        let zipper = XmlNode::new(&document, Content::Elements).zipper();
+
+        assert_eq!(zipper.node.name(), Some("book"));
+        assert_eq!(
+            zipper.node.attributes().collect::<Vec<_>>(),
+            vec![("lang", "en")]
+        );
+        assert_eq!(zipper.child_count(), 2);
+
+        let chapter = zipper.down()?.right()?;
+        assert_eq!(chapter.node.name(), Some("chapter"));
+        assert_eq!(chapter.node.attribute("n"), Some("1"));
+        assert_eq!(chapter.node.text(), "Intro here");
+
+        let em = chapter.down()?;
+        assert_eq!(em.node.name(), Some("em"));
+        assert!(em.is_leaf());
+
+        Ok(())
+    }
+
+    #[test]
+    fn text() -> Result<(), ZipperErr> {
+        let document = Document::parse(XML).unwrap();
+        let zipper = XmlNode::new(&document, Content::Text).zipper();
+
+        // comments are never children
+        assert_eq!(zipper.child_count(), 2);
+
+        let intro = zipper.travel([Down, Right, Down].into_iter())?;
+        assert!(!intro.node.is_element());
+        assert_eq!(intro.node.name(), None);
+        assert_eq!(intro.node.text(), "Intro ");
+        assert_eq!(intro.right()?.down()?.node.text(), "here");
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "html")]
+mod html {
+    use super::*;
+    use scraper::Html;
+
+    const HTML: &str = r#"<!DOCTYPE html><html><head><title>Zippers</title></head><body><p class="lead">Hello <b>world</b></p></body></html>"#;
+
+    #[test]
+    fn elements() -> Result<(), ZipperErr> {
+        let html = Html::parse_document(HTML);
+        let zipper = HtmlNode::new(&html, Content::Elements).zipper();
+
+        assert_eq!(zipper.node.name(), Some("html"));
+
+        let names = zipper
+            .descendants()
+            .filter_map(|zipped| zipped.node.name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["head", "title", "body", "p", "b"]);
+
+        let p = zipper.travel([Down, Right, Down].into_iter())?;
+        assert_eq!(p.node.attribute("class"), Some("lead"));
+        assert_eq!(
+            p.node.attributes().collect::<Vec<_>>(),
+            vec![("class", "lead")]
+        );
+        assert_eq!(p.node.text(), "Hello world");
+
+        Ok(())
+    }
+
+    #[test]
+    fn text() -> Result<(), ZipperErr> {
+        let html = Html::parse_document(HTML);
+        let zipper = HtmlNode::new(&html, Content::Text).zipper();
+
+        let hello = zipper.travel([Down, Right, Down, Down].into_iter())?;
+        assert!(!hello.node.is_element());
+        assert_eq!(hello.node.text(), "Hello ");
+        assert_eq!(hello.right()?.node.name(), Some("b"));
+
+        Ok(())
+    }
+}
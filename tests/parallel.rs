@@ -0,0 +1,131 @@
+#![cfg(feature = "rayon")]
+
+use rayon::iter::ParallelIterator;
+use zippered::zipper::{Step::*, *};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tree {
+    Node(usize),
+    Branch(Vec<Tree>),
+}
+
+impl Zippable for Tree {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        match self {
+            Tree::Node(_) => [].iter().cloned(),
+            Tree::Branch(branch) => branch.iter().cloned(),
+        }
+    }
+}
+
+/// A complete tree of the given `width` and `depth`, whose leaves are numbered left to right.
+fn tree(width: usize, depth: usize, next: &mut usize) -> Tree {
+    if depth == 0 {
+        *next += 1;
+        return Tree::Node(*next);
+    }
+    Tree::Branch((0..width).map(|_| tree(width, depth - 1, next)).collect())
+}
+
+#[test]
+fn par_descendants_matches_descendants() -> Result<(), ZipperErr> {
+    let tree = tree(4, 4, &mut 0);
+
+    for zipper in [tree.zipper(), tree.zipper().down()?.right()?] {
+        let sequential = zipper
+            .descendants()
+            .map(|zipped| (zipped.path().collect::<Vec<_>>(), zipped.node))
+            .collect::<Vec<_>>();
+        let parallel = zipper.par_descendants().collect::<Vec<_>>();
+
+        assert_eq!(parallel, sequential);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn par_find() -> Result<(), ZipperErr> {
+    let tree = tree(3, 3, &mut 0);
+    let zipper = tree.zipper();
+
+    let (path, node) = zipper
+        .par_find(|node| matches!(node, Tree::Node(n) if n % 5 == 0))
+        .unwrap();
+    assert_eq!(node, Tree::Node(5));
+    assert_eq!(path, vec![Down, Down, Right, Down, Right]);
+    assert_eq!(zipper.clone().travel(path.into_iter())?.node, node);
+
+    assert_eq!(zipper.par_find(|node| node == &Tree::Node(100)), None);
+
+    Ok(())
+}
+
+#[test]
+fn par_fold() -> Result<(), ZipperErr> {
+    let tree = tree(5, 3, &mut 0);
+    let zipper = tree.zipper().down()?;
+
+    let (count, sum, deepest) = zipper.par_fold(
+        || (0, 0, 0),
+        |(count, sum, deepest), path, node| {
+            let value = match node {
+                Tree::Node(n) => *n,
+                Tree::Branch(_) => 0,
+            };
+            (count + 1, sum + value, deepest.max(path.len()))
+        },
+        |a, b| (a.0 + b.0, a.1 + b.1, a.2.max(b.2)),
+    );
+
+    assert_eq!(count, 5 + 25);
+    assert_eq!(sum, (1..=25).sum::<usize>());
+    // down to the last leaf of the last branch
+    assert_eq!(deepest, 1 + 2 * (1 + 4));
+
+    Ok(())
+}
+
+/// A graph of `0..4` in which each node leads to the next, and the last back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Ring(usize);
+
+impl Zippable for Ring {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        std::iter::once(Ring((self.0 + 1) % 4))
+    }
+}
+
+impl Identified for Ring {
+    type Id = usize;
+
+    fn id(&self) -> usize {
+        self.0
+    }
+}
+
+#[test]
+fn par_descendants_honours_cycle_policy() -> Result<(), ZipperErr> {
+    for policy in [CyclePolicy::Error, CyclePolicy::Leaf] {
+        let session = Ring(0).session().detect_cycles(policy);
+
+        for zipper in [session.cursor(), session.cursor().down()?.down()?] {
+            let sequential = zipper
+                .descendants()
+                .map(|zipped| (zipped.path().collect::<Vec<_>>(), zipped.node))
+                .collect::<Vec<_>>();
+            let parallel = zipper.par_descendants().collect::<Vec<_>>();
+
+            assert_eq!(parallel, sequential, "{policy:?}");
+        }
+    }
+
+    let leaf = Ring(0).session().detect_cycles(CyclePolicy::Leaf).cursor();
+    let nodes = leaf
+        .par_descendants()
+        .map(|(_, node)| node.0)
+        .collect::<Vec<_>>();
+    assert_eq!(nodes, vec![1, 2, 3, 0]);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+#![cfg(feature = "petgraph")]
+
+use petgraph::{
+    graphmap::DiGraphMap, stable_graph::StableGraph, visit::EdgeRef, Graph, Undirected,
+};
+use zippered::graph::{Edges, GraphNode};
+use zippered::zipper::{Step::*, *};
+
+#[test]
+fn graph_outgoing() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    let three = graph.add_node(3);
+    graph.extend_with_edges([(root, one), (root, two), (one, three)]);
+
+    let zipper = GraphNode::new(&graph, root, Edges::Outgoing).zipper();
+
+    // neighbours follow the order edges were added in
+    let values = zipper
+        .descendants()
+        .map(|zipped| zipped.node.weight().cloned())
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![Some(1), Some(3), Some(2)]);
+
+    let zipped = zipper.down()?.right()?;
+    assert_eq!(zipped.node.id(), two);
+    assert_eq!(zipped.path().collect::<Vec<Step>>(), vec![Down, Right]);
+
+    Ok(())
+}
+
+#[test]
+fn graph_incoming() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    graph.extend_with_edges([(one, root), (two, root), (root, two)]);
+
+    let zipper = GraphNode::new(&graph, root, Edges::Incoming).zipper();
+
+    assert_eq!(zipper.clone().down()?.node.weight(), Some(&1));
+    assert_eq!(zipper.clone().down()?.right()?.node.weight(), Some(&2));
+    assert!(zipper.down()?.right()?.right().is_err());
+
+    Ok(())
+}
+
+#[test]
+fn graph_undirected() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    graph.extend_with_edges([(root, one), (two, root), (root, root)]);
+
+    let zipper = GraphNode::new(&graph, root, Edges::Undirected).zipper();
+
+    // the self loop is only followed once
+    assert_eq!(zipper.child_count(), 3);
+
+    let children = [Down, Right, Right]
+        .into_iter()
+        .scan(zipper, |zipper, step| {
+            *zipper = zipper.clone().step(&step).ok()?;
+            Some(zipper.node.id())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(children, vec![one, two, root]);
+
+    Ok(())
+}
+
+#[test]
+fn undirected_graph() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize, Undirected>::new_undirected();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    let two = graph.add_node(2);
+    graph.extend_with_edges([(root, one), (two, root)]);
+
+    for edges in [Edges::Outgoing, Edges::Incoming, Edges::Undirected] {
+        let zipper = GraphNode::new(&graph, root, edges).zipper();
+
+        assert_eq!(zipper.child_count(), 2);
+        assert_eq!(zipper.clone().down()?.node.id(), one);
+        assert_eq!(zipper.down()?.right()?.node.id(), two);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn stable_graph() -> Result<(), ZipperErr> {
+    let mut graph = StableGraph::<&str, ()>::new();
+    let root = graph.add_node("root");
+    let gone = graph.add_node("gone");
+    let one = graph.add_node("one");
+    let two = graph.add_node("two");
+    graph.extend_with_edges([(root, gone), (root, one), (root, two)]);
+    graph.remove_node(gone);
+
+    let zipper = GraphNode::new(&graph, root, Edges::Outgoing).zipper();
+
+    assert_eq!(zipper.clone().down()?.node.weight(), Some(&"one"));
+    assert_eq!(zipper.down()?.right()?.node.weight(), Some(&"two"));
+
+    Ok(())
+}
+
+#[test]
+fn graph_map() -> Result<(), ZipperErr> {
+    let graph =
+        DiGraphMap::<char, usize>::from_edges([('a', 'c', 1), ('a', 'b', 2), ('b', 'c', 3)]);
+
+    let zipper = GraphNode::new(&graph, 'a', Edges::Outgoing).zipper();
+
+    let nodes = zipper
+        .descendants()
+        .map(|zipped| zipped.node.id())
+        .collect::<Vec<_>>();
+    assert_eq!(nodes, vec!['c', 'b', 'c']);
+
+    let incoming = GraphNode::new(&graph, 'c', Edges::Incoming).zipper();
+    assert_eq!(incoming.clone().down()?.node.id(), 'a');
+    assert_eq!(incoming.down()?.right()?.node.id(), 'b');
+
+    Ok(())
+}
+
+#[test]
+fn cycles() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<usize, usize>::new();
+    let root = graph.add_node(0);
+    let one = graph.add_node(1);
+    graph.extend_with_edges([(root, one), (one, root)]);
+
+    let zipper = GraphNode::new(&graph, root, Edges::Outgoing)
+        .session()
+        .detect_cycles(CyclePolicy::Error)
+        .cursor();
+
+    assert!(matches!(zipper.down()?.down(), Err(ZipperErr::Cycle)));
+
+    Ok(())
+}
+
+#[test]
+fn up_to_another_dependent() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<&str, ()>::new();
+    let app = graph.add_node("app");
+    let cli = graph.add_node("cli");
+    let core = graph.add_node("core");
+    graph.extend_with_edges([(app, core), (cli, core)]);
+
+    let core = GraphNode::new(&graph, app, Edges::Outgoing)
+        .zipper()
+        .down()?;
+    assert_eq!(
+        core.parents()
+            .map(|parent| parent.weight().cloned())
+            .collect::<Vec<_>>(),
+        vec![Some("app"), Some("cli")]
+    );
+
+    let cli = core.up_to(|parent| parent.weight() == Some(&"cli"))?;
+    assert_eq!(cli.node.weight(), Some(&"cli"));
+    assert_eq!(cli.down()?.node.weight(), Some(&"core"));
+
+    Ok(())
+}
+
+#[test]
+fn edge_weights() -> Result<(), ZipperErr> {
+    let mut graph = Graph::<&str, &str>::new();
+    let root = graph.add_node("root");
+    let one = graph.add_node("one");
+    let two = graph.add_node("two");
+    graph.extend_with_edges([
+        (root, one, "first"),
+        (root, two, "second"),
+        (two, root, "back"),
+    ]);
+
+    let zipper = GraphNode::new(&graph, root, Edges::Outgoing).zipper();
+    assert!(zipper.edge().is_none());
+
+    let two = zipper.down()?.right()?;
+    assert_eq!(two.edge().map(|edge| *edge.weight()), Some("second"));
+
+    let root = two.clone().down()?;
+    assert_eq!(root.edge().map(|edge| *edge.weight()), Some("back"));
+    assert_eq!(root.edge().map(|edge| edge.source()), Some(two.node.id()));
+
+    Ok(())
+}
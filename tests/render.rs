@@ -0,0 +1,145 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use zippered::zipper::{Step::*, *};
+
+/// A complete binary tree numbered in breadth-first order, counting how often children are listed.
+#[derive(Debug, Clone)]
+struct Heap {
+    n: usize,
+    listed: Rc<Cell<usize>>,
+}
+
+impl Zippable for Heap {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.listed.set(self.listed.get() + 1);
+        [self.n * 2, self.n * 2 + 1].into_iter().map(|n| Heap {
+            n,
+            listed: self.listed.clone(),
+        })
+    }
+}
+
+fn heap() -> Heap {
+    Heap {
+        n: 1,
+        listed: Rc::new(Cell::new(0)),
+    }
+}
+
+fn label(heap: &Heap) -> String {
+    heap.n.to_string()
+}
+
+#[test]
+fn renders_only_visited_positions() -> Result<(), ZipperErr> {
+    let root = heap();
+    let zipper = root.zipper();
+    assert_eq!(zipper.render(label).to_string(), "1 (focus)");
+
+    let zipper = zipper
+        .travel([Down, Down, Right, Up, Right, Down].into_iter())?
+        .right()?
+        .left()?;
+    let listed = root.listed.get();
+
+    assert_eq!(
+        zipper.render(label).to_string(),
+        "\
+1 (ancestor)
+├── 2
+│   ├── 4
+│   └── 5
+└── 3 (ancestor)
+    ├── 6 (focus)
+    └── 7 (sibling)"
+    );
+    assert_eq!(root.listed.get(), listed);
+
+    Ok(())
+}
+
+#[test]
+fn renders_around_the_focus() -> Result<(), ZipperErr> {
+    let zipper = heap()
+        .zipper()
+        .travel([Down, Right, Down, Down, Right, Down].into_iter())?;
+    assert_eq!(zipper.node.n, 26);
+
+    assert_eq!(
+        zipper.render(label).around(1).to_string(),
+        "\
+13 (ancestor)
+└── 26 (focus)"
+    );
+    assert_eq!(
+        zipper.render(label).around(2).to_string(),
+        "\
+6 (ancestor)
+├── 12
+└── 13 (ancestor)
+    └── 26 (focus)"
+    );
+    assert_eq!(zipper.render(label).around(0).to_string(), "26 (focus)");
+
+    Ok(())
+}
+
+#[test]
+fn expands_unvisited_children() -> Result<(), ZipperErr> {
+    let zipper = heap().zipper().down()?.right()?;
+
+    assert_eq!(
+        zipper.render(label).around(1).expand().to_string(),
+        "\
+1 (ancestor)
+├── 2 (sibling)
+│   ├── 4
+│   └── 5
+└── 3 (focus)
+    ├── 6
+    └── 7"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dot_numbers_the_journey() -> Result<(), ZipperErr> {
+    let zipper = heap()
+        .zipper()
+        .travel([Down, Right, Down, Up, Left].into_iter())?;
+
+    assert_eq!(
+        zipper.dot(label).to_string(),
+        r#"digraph zipper {
+    node [shape=box];
+    0 [label="1"];
+    1 [label="2", style=filled, fillcolor=gold];
+    0 -> 1 [color=gray, arrowhead=none];
+    2 [label="3"];
+    0 -> 2 [color=gray, arrowhead=none];
+    3 [label="6"];
+    2 -> 3 [color=gray, arrowhead=none];
+    0 -> 1 [label="1 Down", color=red, fontcolor=red, constraint=false];
+    1 -> 2 [label="2 Right", color=red, fontcolor=red, constraint=false];
+    2 -> 3 [label="3 Down", color=red, fontcolor=red, constraint=false];
+    3 -> 2 [label="4 Up", color=red, fontcolor=red, constraint=false];
+    2 -> 1 [label="5 Left", color=red, fontcolor=red, constraint=false];
+}"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dot_escapes_labels() {
+    let zipper = heap().zipper();
+
+    assert_eq!(
+        zipper.dot(|heap| format!("\"{}\"\n\\", heap.n)).to_string(),
+        r#"digraph zipper {
+    node [shape=box];
+    0 [label="\"1\"\n\\", style=filled, fillcolor=gold];
+}"#
+    );
+}
@@ -0,0 +1,145 @@
+use std::{cell::Cell, rc::Rc};
+use zippered::zipper::{Step::*, *};
+
+#[derive(Debug, Clone)]
+struct Counted {
+    value: usize,
+    calls: Rc<Cell<usize>>,
+}
+
+impl Counted {
+    fn new(value: usize, calls: &Rc<Cell<usize>>) -> Self {
+        Self {
+            value,
+            calls: calls.clone(),
+        }
+    }
+}
+
+// every node below 100 has the children value * 10 + 1 and value * 10 + 2
+impl Zippable for Counted {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.calls.set(self.calls.get() + 1);
+
+        let values = if self.value < 100 {
+            vec![self.value * 10 + 1, self.value * 10 + 2]
+        } else {
+            vec![]
+        };
+
+        values
+            .into_iter()
+            .map(|value| Counted::new(value, &self.calls))
+    }
+}
+
+#[test]
+fn cursors_share_cache() -> Result<(), ZipperErr> {
+    let calls = Rc::new(Cell::new(0));
+    let session = Counted::new(1, &calls).session();
+
+    let left = session.cursor().down()?.down()?;
+    let calls_after_left = calls.get();
+
+    let right = session.cursor().down()?.down()?;
+
+    assert_eq!(left.node.value, 111);
+    assert_eq!(right.node.value, 111);
+    assert_eq!(calls.get(), calls_after_left);
+
+    Ok(())
+}
+
+#[test]
+fn cursors_share_bookmarks() -> Result<(), ZipperErr> {
+    let calls = Rc::new(Cell::new(0));
+    let session = Counted::new(1, &calls).session();
+
+    session.cursor().down()?.right()?.mark("twelve");
+
+    let jumped = session.cursor().jump("twelve")?;
+
+    assert_eq!(jumped.node.value, 12);
+    assert_eq!(jumped.journey().collect::<Vec<Step>>(), vec![Down, Right]);
+    assert_eq!(
+        session.bookmarks(),
+        vec![("twelve".to_string(), vec![Down, Right])]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cursors_move_independently() -> Result<(), ZipperErr> {
+    let calls = Rc::new(Cell::new(0));
+    let session = Counted::new(1, &calls).session();
+
+    let left = session.cursor().down()?;
+    let right = session.cursor().down()?.right()?;
+
+    assert_eq!(left.node.value, 11);
+    assert_eq!(right.node.value, 12);
+    assert_eq!(left.path().collect::<Vec<Step>>(), vec![Down]);
+    assert_eq!(right.path().collect::<Vec<Step>>(), vec![Down, Right]);
+
+    Ok(())
+}
+
+#[test]
+fn list_active_cursors() -> Result<(), ZipperErr> {
+    let calls = Rc::new(Cell::new(0));
+    let session = Counted::new(1, &calls).session();
+
+    let left = session.cursor().down()?;
+    let right = session.cursor().down()?.right()?.down()?;
+
+    assert_eq!(left.cursor(), Some(0));
+    assert_eq!(right.cursor(), Some(1));
+    assert_eq!(
+        session.cursors(),
+        vec![(0, vec![Down]), (1, vec![Down, Right, Down])]
+    );
+
+    // moving a cursor updates its position, cloning it does not create a new one
+    let left = left.clone().right()?;
+    assert_eq!(
+        session.cursors(),
+        vec![(0, vec![Down, Right]), (1, vec![Down, Right, Down])]
+    );
+
+    // dropping every position of a cursor deactivates it
+    drop(right);
+    assert_eq!(session.cursors(), vec![(0, vec![Down, Right])]);
+
+    // a zipper can hand out further cursors of its own session
+    let another = left.session().cursor();
+    assert_eq!(another.cursor(), Some(2));
+    assert_eq!(session.cursors(), vec![(0, vec![Down, Right]), (2, vec![])]);
+
+    Ok(())
+}
+
+#[test]
+fn stats_count_cache_hits_and_children_calls() -> Result<(), ZipperErr> {
+    let calls = Rc::new(Cell::new(0));
+    let session = Counted::new(1, &calls).session();
+    assert_eq!(session.stats(), CacheStats::default());
+
+    let zipper = session.cursor().down()?.right()?.left()?.down()?;
+    session.cursor().down()?;
+
+    let stats = zipper.stats();
+    assert_eq!(stats, session.stats());
+    assert_eq!(
+        stats,
+        CacheStats {
+            hits: 2,
+            misses: 3,
+            entries: 3,
+            children_calls: 3,
+        }
+    );
+    assert_eq!(stats.children_calls, calls.get());
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use zippered::similarity::*;
+use zippered::zipper::{Step::*, *};
+
+#[derive(Debug, Clone)]
+struct Labelled(char, Vec<Labelled>);
+
+impl Zippable for Labelled {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        self.1.iter().cloned()
+    }
+}
+
+fn tree(label: char, children: impl IntoIterator<Item = Labelled>) -> Labelled {
+    Labelled(label, children.into_iter().collect())
+}
+
+fn leaf(label: char) -> Labelled {
+    tree(label, [])
+}
+
+fn same(a: &Labelled, b: &Labelled) -> bool {
+    a.0 == b.0
+}
+
+/// An infinite binary tree, numbered breadth-first.
+#[derive(Debug, Clone)]
+struct Binary(usize);
+
+impl Zippable for Binary {
+    fn children(&self) -> impl Iterator<Item = Self> + '_ {
+        [Binary(self.0 * 2), Binary(self.0 * 2 + 1)].into_iter()
+    }
+}
+
+#[test]
+fn edit_distance_zhang_shasha() {
+    // the example from Zhang & Shasha's paper
+    let one = tree(
+        'f',
+        [tree('d', [leaf('a'), tree('c', [leaf('b')])]), leaf('e')],
+    );
+    let two = tree(
+        'f',
+        [tree('c', [tree('d', [leaf('a'), leaf('b')])]), leaf('e')],
+    );
+
+    assert_eq!(edit_distance(&one.zipper(), &two.zipper(), None, same), 2);
+    assert_eq!(edit_distance(&two.zipper(), &one.zipper(), None, same), 2);
+    assert_eq!(edit_distance(&one.zipper(), &one.zipper(), None, same), 0);
+    assert_eq!(
+        edit_distance(&one.zipper(), &leaf('f').zipper(), None, same),
+        5
+    );
+}
+
+#[test]
+fn edit_distance_from_focus() -> Result<(), ZipperErr> {
+    let one = tree('r', [tree('a', [leaf('b'), leaf('c')])]);
+    let two = tree('s', [leaf('x'), tree('a', [leaf('b'), leaf('c')])]);
+
+    let a = one.zipper().down()?;
+    let b = two.zipper().travel([Down, Right].into_iter())?;
+
+    assert_eq!(edit_distance(&a, &b, None, same), 0);
+    assert_eq!(edit_distance(&one.zipper(), &two.zipper(), None, same), 2);
+
+    Ok(())
+}
+
+#[test]
+fn edit_distance_depth() {
+    let one = tree('a', [tree('b', [leaf('c')])]);
+    let two = tree('a', [tree('b', [leaf('d'), leaf('e')])]);
+
+    assert_eq!(
+        edit_distance(&one.zipper(), &two.zipper(), Some(1), same),
+        0
+    );
+    assert_eq!(
+        edit_distance(&one.zipper(), &two.zipper(), Some(2), same),
+        2
+    );
+
+    // an infinite tree compared only near the root
+    let distance = edit_distance(&Binary(1).zipper(), &Binary(1).zipper(), Some(3), |a, b| {
+        a.0 == b.0
+    });
+    assert_eq!(distance, 0);
+}
+
+#[test]
+fn path_overlap_counts_matching_paths() -> Result<(), ZipperErr> {
+    let one = tree('a', [leaf('b'), leaf('c')]);
+    let two = tree('a', [leaf('b'), leaf('x'), leaf('d')]);
+
+    // {a, b} match out of {a, b, c/x, d}
+    assert_eq!(path_overlap(&one.zipper(), &two.zipper(), None, same), 0.5);
+    assert_eq!(path_overlap(&one.zipper(), &one.zipper(), None, same), 1.0);
+    assert_eq!(
+        path_overlap(&one.zipper(), &two.zipper(), Some(0), same),
+        1.0
+    );
+
+    let b = one.zipper().down()?;
+    assert_eq!(path_overlap(&b, &two.zipper(), None, same), 0.0);
+
+    Ok(())
+}
+
+#[test]
+fn descendant_jaccard_ignores_position() {
+    let one = tree('a', [leaf('b'), tree('c', [leaf('d')])]);
+    let two = tree('z', [leaf('d'), leaf('c'), leaf('e')]);
+
+    // {c, d} shared out of {b, c, d, e}
+    assert_eq!(
+        descendant_jaccard(&one.zipper(), &two.zipper(), None, |n| n.0),
+        0.5
+    );
+    // {c} shared out of {b, c, d, e}
+    assert_eq!(
+        descendant_jaccard(&one.zipper(), &two.zipper(), Some(1), |n| n.0),
+        0.25
+    );
+    assert_eq!(
+        descendant_jaccard(&leaf('a').zipper(), &leaf('b').zipper(), None, |n| n.0),
+        1.0
+    );
+
+    let jaccard = descendant_jaccard(&Binary(1).zipper(), &Binary(2).zipper(), Some(2), |n| n.0);
+    // {2..=7} and {4, 5, 8..=11}
+    assert_eq!(jaccard, 0.2);
+}
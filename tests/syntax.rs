@@ -0,0 +1,97 @@
+#![cfg(feature = "tree-sitter")]
+
+use tree_sitter::{Parser, Tree};
+use zippered::syntax::{Children, SyntaxNode};
+use zippered::zipper::{Step::*, *};
+
+const SOURCE: &str = r#"{"a": [1, true], "b": null}"#;
+
+fn parse() -> Tree {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_json::LANGUAGE.into())
+        .unwrap();
+    parser.parse(SOURCE, None).unwrap()
+}
+
+#[test]
+fn named() -> Result<(), ZipperErr> {
+    let tree = parse();
+    let zipper = SyntaxNode::new(tree.root_node(), Children::Named).zipper();
+
+    assert_eq!(zipper.node.kind(), "document");
+    assert_eq!(zipper.node.field(), None);
+
+    let kinds = zipper
+        .descendants()
+        .map(|zipped| zipped.node.kind())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            "object",
+            "pair",
+            "string",
+            "string_content",
+            "array",
+            "number",
+            "true",
+            "pair",
+            "string",
+            "string_content",
+            "null"
+        ]
+    );
+
+    let array = zipper.travel([Down, Down, Down, Right].into_iter())?;
+    assert_eq!(array.node.kind(), "array");
+    assert_eq!(array.node.field(), Some("value"));
+    assert_eq!(array.node.byte_range(), 6..15);
+    assert_eq!(array.node.text(SOURCE.as_bytes()), Some("[1, true]"));
+
+    let key = array.left()?;
+    assert_eq!(key.node.field(), Some("key"));
+    assert_eq!(key.node.text(SOURCE.as_bytes()), Some(r#""a""#));
+
+    Ok(())
+}
+
+#[test]
+fn all() -> Result<(), ZipperErr> {
+    let tree = parse();
+    let zipper = SyntaxNode::new(tree.root_node(), Children::All).zipper();
+
+    let object = zipper.down()?;
+    let kinds = object
+        .descendants()
+        .max_depth(1)
+        .map(|zipped| zipped.node.kind())
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["{", "pair", ",", "pair", "}"]);
+
+    let colon = object.travel([Down, Right, Down, Right].into_iter())?;
+    assert_eq!(colon.node.kind(), ":");
+    assert_eq!(colon.node.field(), None);
+    assert!(!colon.node.node().is_named());
+
+    Ok(())
+}
+
+#[test]
+fn back_along_journey() -> Result<(), ZipperErr> {
+    let tree = parse();
+    let zipper = SyntaxNode::new(tree.root_node(), Children::Named).zipper();
+
+    let zipped = zipper.travel([Down, Down, Right, Down, Right].into_iter())?;
+    assert_eq!(zipped.node.kind(), "null");
+
+    let back = zipped.back()?.back()?;
+    assert_eq!(back.node.kind(), "pair");
+    assert_eq!(back.node.byte_range(), 17..26);
+    assert_eq!(
+        back.journey().collect::<Vec<_>>(),
+        vec![Down, Down, Right, Down, Right, Back, Back]
+    );
+
+    Ok(())
+}
@@ -280,6 +280,19 @@ fn down_left_fail() -> Result<(), ZipperErr> {
     Ok(())
 }
 
+#[test]
+fn down_down_left_fail() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![Tree::Branch(vec![Tree::Node(1)]), Tree::Node(2)]);
+
+    let zipped = tree.zipper().down()?.down()?;
+    assert!(!zipped.has_left());
+
+    let result = zipped.left();
+
+    assert!(matches!(result, Err(ZipperErr::CannotGoLeft)));
+    Ok(())
+}
+
 #[test]
 fn down_down_up_up_down_down() -> Result<(), ZipperErr> {
     let tree = Tree::Branch(vec![Tree::Branch(vec![Tree::Node(1)]), Tree::Node(2)]);
@@ -298,3 +311,167 @@ fn down_down_up_up_down_down() -> Result<(), ZipperErr> {
 
     Ok(())
 }
+
+#[test]
+fn position() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![
+        Tree::Branch(vec![Tree::Node(1)]),
+        Tree::Node(2),
+        Tree::Node(3),
+    ]);
+
+    let root = tree.zipper();
+    assert_eq!(root.depth(), 0);
+    assert_eq!(root.index(), None);
+    assert!(root.is_root());
+    assert!(!root.is_leaf());
+    assert!(!root.has_left());
+    assert!(!root.has_right());
+    assert_eq!(root.child_count(), 3);
+
+    let first = root.down()?;
+    assert_eq!(first.depth(), 1);
+    assert_eq!(first.index(), Some(0));
+    assert!(!first.is_root());
+    assert!(!first.is_leaf());
+    assert!(!first.has_left());
+    assert!(first.has_right());
+    assert_eq!(first.child_count(), 1);
+
+    let leaf = first.clone().down()?;
+    assert_eq!(leaf.depth(), 2);
+    assert_eq!(leaf.index(), Some(0));
+    assert!(leaf.is_leaf());
+    assert!(!leaf.has_left());
+    assert!(!leaf.has_right());
+
+    let last = first.right()?.right()?;
+    assert_eq!(last.depth(), 1);
+    assert_eq!(last.index(), Some(2));
+    assert!(last.is_leaf());
+    assert!(last.has_left());
+    assert!(!last.has_right());
+    assert_eq!(last.child_count(), 0);
+
+    // the checks must not move the zipper
+    assert_eq!(last.path().collect::<Vec<Step>>(), vec![Down, Right, Right]);
+
+    Ok(())
+}
+
+#[test]
+fn mark_jump() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![Tree::Branch(vec![Tree::Node(1)]), Tree::Node(2)]);
+
+    let zipped = tree.zipper().down()?.down()?;
+    zipped.mark("one");
+
+    let zipped = zipped.up()?.right()?.jump("one")?;
+    let result = zipped.node.clone();
+
+    assert_eq!(result, Tree::Node(1));
+    assert_eq!(zipped.path().collect::<Vec<Step>>(), vec![Down, Down]);
+    assert_eq!(
+        zipped.journey().collect::<Vec<Step>>(),
+        vec![Down, Down, Up, Right, Up, Down, Down]
+    );
+    assert_eq!(result, tree.zipper().travel(zipped.path())?.node);
+    assert_eq!(result, tree.zipper().travel(zipped.journey())?.node);
+
+    Ok(())
+}
+
+#[test]
+fn mark_jump_root() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![Tree::Branch(vec![Tree::Node(1)]), Tree::Node(2)]);
+
+    let root = tree.zipper();
+    root.mark("root");
+
+    let zipped = root.down()?.right()?.jump("root")?;
+
+    assert_eq!(zipped.node, tree);
+    assert_eq!(zipped.path().collect::<Vec<Step>>(), vec![]);
+    assert_eq!(
+        zipped.journey().collect::<Vec<Step>>(),
+        vec![Down, Right, Up]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn bookmarks_shared_with_clones() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![Tree::Branch(vec![Tree::Node(1)]), Tree::Node(2)]);
+
+    let zipped = tree.zipper().down()?;
+    let cloned = zipped.clone();
+    zipped.right()?.mark("two");
+    cloned.mark("branch");
+
+    assert_eq!(
+        cloned.bookmarks(),
+        vec![
+            ("branch".to_string(), vec![Down]),
+            ("two".to_string(), vec![Down, Right])
+        ]
+    );
+    assert_eq!(cloned.clone().jump("two")?.node, Tree::Node(2));
+
+    assert!(cloned.unmark("two"));
+    assert!(!cloned.unmark("two"));
+    assert!(matches!(
+        cloned.jump("two"),
+        Err(ZipperErr::UnknownBookmark)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn descendants() -> Result<(), ZipperErr> {
+    let tree = Tree::Branch(vec![
+        Tree::Branch(vec![Tree::Node(1), Tree::Node(2)]),
+        Tree::Node(3),
+    ]);
+
+    let descendants = tree.zipper().descendants().collect::<Vec<_>>();
+
+    assert_eq!(
+        descendants
+            .iter()
+            .map(|zipped| zipped.node.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            Tree::Branch(vec![Tree::Node(1), Tree::Node(2)]),
+            Tree::Node(1),
+            Tree::Node(2),
+            Tree::Node(3),
+        ]
+    );
+    assert_eq!(
+        descendants
+            .iter()
+            .map(|zipped| zipped.path().collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        vec![
+            vec![Down],
+            vec![Down, Down],
+            vec![Down, Down, Right],
+            vec![Down, Right],
+        ]
+    );
+    for zipped in descendants {
+        assert_eq!(zipped.node, tree.zipper().travel(zipped.journey())?.node);
+    }
+
+    let from_branch = tree.zipper().down()?.descendants().collect::<Vec<_>>();
+    assert_eq!(from_branch.len(), 2);
+    assert_eq!(
+        from_branch[1].path().collect::<Vec<_>>(),
+        vec![Down, Down, Right]
+    );
+    assert_eq!(from_branch[1].clone().up()?.up()?.node, tree);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+use zippered::zipper::{Step::*, *};
+
+/// A game of noughts and crosses, as a board of nine cells filled in turn by `X` and `O`.
+fn moves(board: &str) -> Vec<String> {
+    let turn = match board.chars().filter(|&c| c != '.').count() % 2 {
+        0 => 'X',
+        _ => 'O',
+    };
+
+    board
+        .char_indices()
+        .filter(|&(_, c)| c == '.')
+        .map(|(i, _)| {
+            let mut next = board.to_string();
+            next.replace_range(i..=i, &turn.to_string());
+            next
+        })
+        .collect()
+}
+
+#[test]
+fn generated_tree() -> Result<(), ZipperErr> {
+    let zipper = from_fn(".........".to_string(), |board: &String| moves(board));
+
+    assert_eq!(zipper.child_count(), 9);
+    assert_eq!(zipper.node.as_str(), ".........");
+
+    let zipper = zipper.travel([Down, Right, Right, Down].into_iter())?;
+    assert_eq!(zipper.node.as_str(), "O.X......");
+    assert_eq!(zipper.child_count(), 7);
+
+    let zipper = zipper.right()?.up()?;
+    assert_eq!(zipper.node.value, "..X......");
+
+    Ok(())
+}
+
+#[test]
+fn foreign_type() -> Result<(), ZipperErr> {
+    let edges = HashMap::from([("a", vec!["b", "c"]), ("c", vec!["d"])]);
+    let zipper = from_fn("a", |node: &&str| {
+        edges.get(node).cloned().unwrap_or_default()
+    });
+
+    let names = zipper
+        .descendants()
+        .map(|zipped| *zipped.node)
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["b", "c", "d"]);
+
+    assert!(zipper.clone().down()?.is_leaf());
+    assert_eq!(*zipper.down()?.right()?.down()?.node, "d");
+
+    Ok(())
+}
+
+#[test]
+fn session_caches_unfolded_children() -> Result<(), ZipperErr> {
+    let calls = Cell::new(0);
+    let session = Unfold::new(0, |&n: &usize| {
+        calls.set(calls.get() + 1);
+        if n < 3 {
+            vec![n + 1, n + 10]
+        } else {
+            vec![]
+        }
+    })
+    .session();
+
+    let zipper = session.cursor().down()?.down()?.right()?;
+    assert_eq!(*zipper.node, 11);
+    let before = calls.get();
+
+    let zipper = zipper.up()?.down()?.right()?;
+    assert_eq!(*zipper.node, 11);
+    assert_eq!(calls.get(), before);
+
+    Ok(())
+}
@@ -18,3 +18,17 @@ fn test_all() {
     assert!(u.zipper().left().is_err());
     assert!(u.zipper().right().is_err());
 }
+
+#[test]
+fn position() {
+    let zipper = Usize(42).zipper();
+
+    assert_eq!(zipper.node.0, 42);
+    assert_eq!(zipper.depth(), 0);
+    assert_eq!(zipper.index(), None);
+    assert!(zipper.is_root());
+    assert!(zipper.is_leaf());
+    assert!(!zipper.has_left());
+    assert!(!zipper.has_right());
+    assert_eq!(zipper.child_count(), 0);
+}
@@ -0,0 +1,222 @@
+//! `#[derive(Zippable)]` for `zippered`, re-exported as `zippered::zipper::Zippable` by its `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Fields, GenericArgument,
+    PathArguments, Type,
+};
+
+/// Derives `Zippable` for a struct or enum whose children are held in fields marked `#[zippable(children)]`,
+/// or in every field of a struct or variant marked `#[zippable(children)]`.
+///
+/// A field may hold a single child, or any nesting of `Vec`, `Option`, `Box`, `Rc` and `Arc` around children.
+/// Children are visited field by field, in declaration order. Values without marked fields have no children.
+///
+/// ```ignore
+/// #[derive(Clone, Zippable)]
+/// enum Tree {
+///     Node(usize),
+///     #[zippable(children)]
+///     Branch(Vec<Tree>),
+/// }
+/// ```
+#[proc_macro_derive(Zippable, attributes(zippable))]
+pub fn derive_zippable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    // derived `Clone` impls bound type parameters, which `Zippable` then needs too
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { Self: ::std::clone::Clone });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let arms = match &input.data {
+        Data::Struct(data) => {
+            let (pattern, children) = fields(&data.fields, is_children(&input.attrs)?)?;
+            vec![quote! { Self #pattern => #children }]
+        }
+        Data::Enum(_) if input.attrs.iter().any(is_zippable) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[zippable(children)]` cannot mark a whole enum, mark its variants or fields instead",
+            ))
+        }
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let ident = &variant.ident;
+                let (pattern, children) = fields(&variant.fields, is_children(&variant.attrs)?)?;
+                Ok(quote! { Self::#ident #pattern => #children })
+            })
+            .collect::<syn::Result<_>>()?,
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Zippable cannot be derived for unions",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::zippered::zipper::Zippable for #name #ty_generics #where_clause {
+            fn children(&self) -> impl ::std::iter::Iterator<Item = Self> + '_ {
+                let children: ::std::boxed::Box<dyn ::std::iter::Iterator<Item = Self> + '_> = match self {
+                    #(#arms,)*
+                };
+                children
+            }
+        }
+    })
+}
+
+/// Whether `attrs` contain `#[zippable(children)]`, rejecting any other `zippable` attribute.
+fn is_children(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut children = false;
+
+    for attr in attrs.iter().filter(|attr| is_zippable(attr)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("children") {
+                children = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `children`"))
+            }
+        })?;
+    }
+
+    Ok(children)
+}
+
+/// Whether `attr` is a `zippable` attribute.
+fn is_zippable(attr: &Attribute) -> bool {
+    attr.path().is_ident("zippable")
+}
+
+/// A pattern binding the children fields of `fields`, along with a boxed iterator over their children.
+fn fields(fields: &Fields, all: bool) -> syn::Result<(TokenStream2, TokenStream2)> {
+    let mut bindings = Vec::new();
+    let mut iterators = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let children = all || is_children(&field.attrs)?;
+        let binding = match &field.ident {
+            Some(ident) => ident.clone(),
+            None => format_ident!("__{}", index),
+        };
+
+        if children {
+            iterators.push(iterate(&field.ty, quote! { #binding }));
+        }
+        bindings.push((field.ident.clone(), binding, children));
+    }
+
+    let pattern = match fields {
+        Fields::Named(_) => {
+            let names = bindings
+                .iter()
+                .filter(|(_, _, children)| *children)
+                .map(|(_, binding, _)| binding);
+            quote! { { #(#names,)* .. } }
+        }
+        Fields::Unnamed(_) => {
+            let positions = bindings
+                .iter()
+                .map(|(_, binding, children)| match children {
+                    true => quote! { #binding },
+                    false => quote! { _ },
+                });
+            quote! { ( #(#positions),* ) }
+        }
+        Fields::Unit => quote! {},
+    };
+
+    let children = iterators
+        .into_iter()
+        .reduce(|chained, next| quote! { ::std::iter::Iterator::chain(#chained, #next) })
+        .unwrap_or_else(|| quote! { ::std::iter::empty() });
+
+    Ok((pattern, quote! { ::std::boxed::Box::new(#children) }))
+}
+
+/// An iterator over the children held by `value`, a reference to a `ty`.
+fn iterate(ty: &Type, value: TokenStream2) -> TokenStream2 {
+    match wrapper(ty) {
+        Some(("Vec" | "Option", inner)) => {
+            let item = iterate(inner, quote! { item });
+            quote! { #value.iter().flat_map(|item| #item) }
+        }
+        Some(("Box" | "Rc" | "Arc", inner)) => iterate(inner, quote! { (&**#value) }),
+        _ => quote! { ::std::iter::once(::std::clone::Clone::clone(#value)) },
+    }
+}
+
+/// The name and single type argument of a known wrapper type such as `Vec<T>`.
+fn wrapper(ty: &Type) -> Option<(&'static str, &Type)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    let name = ["Vec", "Option", "Box", "Rc", "Arc"]
+        .into_iter()
+        .find(|name| segment.ident == name)?;
+
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some((name, inner)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The error message of expanding `input`, which must fail.
+    fn error(input: DeriveInput) -> String {
+        expand(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn rejects_unknown_attributes() {
+        assert_eq!(
+            error(parse_quote! {
+                #[zippable(bogus)]
+                struct S(Vec<S>);
+            }),
+            "expected `children`"
+        );
+        assert_eq!(
+            error(parse_quote! {
+                struct S(#[zippable(bogus)] Vec<S>);
+            }),
+            "expected `children`"
+        );
+    }
+
+    #[test]
+    fn rejects_marked_enums() {
+        assert_eq!(
+            error(parse_quote! {
+                #[zippable(children)]
+                enum Tree {
+                    Node(usize),
+                    Branch(Vec<Tree>),
+                }
+            }),
+            "`#[zippable(children)]` cannot mark a whole enum, mark its variants or fields instead"
+        );
+    }
+}